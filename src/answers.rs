@@ -0,0 +1,165 @@
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Mutex;
+use strum_macros::{Display, EnumString};
+
+// what the website said about a submitted answer
+#[derive(EnumString, Display, Clone, Copy, PartialEq, Debug)]
+#[strum(serialize_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug)]
+struct Submission {
+    part: u8,
+    verdict: Verdict,
+    value: String,
+}
+
+/*
+ * Puzzle metadata and submitted answers of one day, stored in `data/YYYY/answers/NN.txt`.
+ * Each line is either `title <puzzle title>` or `<part> <verdict> <answer>`, e.g.
+ *
+ *   title Calorie Counting
+ *   1 too_low 24000
+ *   1 correct 67450
+ */
+#[derive(Debug, Default)]
+struct AnswerStore {
+    title: Option<String>,
+    submissions: Vec<Submission>,
+}
+
+// year and day of the solver that is currently running, set by the runner
+static CURRENT_DAY: Mutex<Option<(String, String)>> = Mutex::new(None);
+
+fn store_path(year: &str, day: &str) -> String {
+    format!("data/{year}/answers/{day}.txt")
+}
+
+fn load(year: &str, day: &str) -> AnswerStore {
+    let mut store = AnswerStore::default();
+    let contents = match fs::read_to_string(store_path(year, day)) {
+        Ok(contents) => contents,
+        Err(_) => return store,
+    };
+
+    for (line_number, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(title) = line.strip_prefix("title ") {
+            store.title = Some(title.trim().to_string());
+            continue;
+        }
+        let mut tokens = line.split_whitespace();
+        match (
+            tokens.next().and_then(|it| u8::from_str(it).ok()),
+            tokens.next().and_then(|it| Verdict::from_str(it).ok()),
+            tokens.next(),
+        ) {
+            (Some(part), Some(verdict), Some(value)) => store.submissions.push(Submission {
+                part,
+                verdict,
+                value: value.to_string(),
+            }),
+            _ => println!("Ignoring malformed line {} in {}: {line}", line_number + 1, store_path(year, day)),
+        }
+    }
+    store
+}
+
+fn save(year: &str, day: &str, store: &AnswerStore) {
+    let mut contents = String::new();
+    if let Some(title) = &store.title {
+        contents.push_str(&format!("title {title}\n"));
+    }
+    for submission in &store.submissions {
+        contents.push_str(&format!("{} {} {}\n", submission.part, submission.verdict, submission.value));
+    }
+
+    let path = store_path(year, day);
+    let dir = Path::new(&path).parent().unwrap();
+    fs::create_dir_all(dir).expect("Could not create answers directory");
+    fs::write(&path, contents).expect("Could not write answers file");
+}
+
+pub fn set_current_day(year: &str, day: &str) {
+    *CURRENT_DAY.lock().unwrap() = Some((year.to_string(), day.to_string()));
+}
+
+/*
+ * Compares a freshly computed answer against the store of the running day and warns if it
+ * is a known wrong answer, differs from the known correct one or violates a too high/too low
+ * bound.
+ */
+pub fn check(part: u8, answer: impl Display) {
+    let (year, day) = match CURRENT_DAY.lock().unwrap().clone() {
+        Some(current) => current,
+        None => return,
+    };
+    let answer = answer.to_string();
+    let store = load(&year, &day);
+
+    for submission in store.submissions.iter().filter(|it| it.part == part) {
+        let bound = submission.value.parse::<i128>().ok();
+        let numeric_answer = answer.parse::<i128>().ok();
+        match (submission.verdict, bound, numeric_answer) {
+            (Verdict::Correct, _, _) if submission.value == answer => {
+                println!("Part {part}: {answer} matches the recorded correct answer")
+            }
+            (Verdict::Correct, _, _) => {
+                println!("Warning: part {part}: {answer} differs from the recorded correct answer {}", submission.value)
+            }
+            _ if submission.value == answer => {
+                println!("Warning: part {part}: {answer} has already been submitted and was {}", submission.verdict)
+            }
+            (Verdict::TooHigh, Some(bound), Some(value)) if value > bound => {
+                println!("Warning: part {part}: {answer} is above {bound}, which was already too high")
+            }
+            (Verdict::TooLow, Some(bound), Some(value)) if value < bound => {
+                println!("Warning: part {part}: {answer} is below {bound}, which was already too low")
+            }
+            _ => {}
+        }
+    }
+}
+
+// `record YYYY NN title <title>` or `record YYYY NN <part> <answer> <verdict>`
+pub fn record(year: &str, day: &str, args: &[&str]) {
+    let mut store = load(year, day);
+    match args {
+        ["title", title @ ..] if !title.is_empty() => store.title = Some(title.join(" ")),
+        [part, value, verdict] => match (u8::from_str(part), Verdict::from_str(verdict)) {
+            (Ok(part), Ok(verdict)) => store.submissions.push(Submission {
+                part,
+                verdict,
+                value: value.to_string(),
+            }),
+            _ => {
+                println!("Expected a part number and one of correct, wrong, too_high, too_low");
+                return;
+            }
+        },
+        _ => {
+            println!("Usage: record <year> <day> title <title> | record <year> <day> <part> <answer> <verdict>");
+            return;
+        }
+    }
+    save(year, day, &store);
+    print(year, day);
+}
+
+pub fn print(year: &str, day: &str) {
+    let store = load(year, day);
+    println!("{year} day {day}: {}", store.title.as_deref().unwrap_or("(no title recorded)"));
+    for submission in &store.submissions {
+        println!("  part {}: {} ({})", submission.part, submission.value, submission.verdict);
+    }
+}
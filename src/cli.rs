@@ -0,0 +1,21 @@
+use std::env;
+use std::str::FromStr;
+
+/*
+ * Access to `--flag` and `--option value` command line arguments, which may follow the year
+ * and day number and are interpreted by the runner or by individual days.
+ */
+pub fn has_flag(name: &str) -> bool {
+    env::args().any(|it| it == name)
+}
+
+pub fn option_value(name: &str) -> Option<String> {
+    env::args().skip_while(|it| it != name).nth(1)
+}
+
+// panics with a readable message if the value does not parse
+pub fn parsed_option<T: FromStr>(name: &str) -> Option<T> {
+    option_value(name).map(|value| {
+        T::from_str(&value).unwrap_or_else(|_| panic!("Invalid value {value:?} for {name}"))
+    })
+}
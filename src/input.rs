@@ -0,0 +1,19 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+
+/*
+ * Buffered access to puzzle inputs for solvers which process their input line by line (or byte
+ * by byte) and therefore never need the whole file in memory.
+ */
+pub fn open(path: &str) -> BufReader<File> {
+    let file = File::open(path).unwrap_or_else(|err| panic!("Could not read file {path}: {err}"));
+    BufReader::new(file)
+}
+
+pub fn lines(path: &str) -> impl Iterator<Item = String> {
+    open(path).lines().map(|line| line.expect("Could not read line"))
+}
+
+pub fn bytes(path: &str) -> impl Iterator<Item = u8> {
+    open(path).bytes().map(|byte| byte.expect("Could not read byte"))
+}
@@ -0,0 +1,204 @@
+use std::fmt;
+
+/*
+ * Set of integers stored as sorted, disjoint and non-adjacent inclusive ranges, e.g. the
+ * sections assigned to elves (day 04) or the positions of a row covered by sensors (day 15).
+ * Inserting a range merges it with all ranges it overlaps or touches.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet {
+    ranges: Vec<(i64, i64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet { ranges: vec![] }
+    }
+
+    // empty set if start > end
+    pub fn from_range(start: i64, end: i64) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        set.insert(start, end);
+        set
+    }
+
+    pub fn insert(&mut self, start: i64, end: i64) {
+        if start > end {
+            return;
+        }
+        // ranges entirely before and entirely after the new one stay untouched
+        let first = self.ranges.partition_point(|&(_, e)| e < start - 1);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end + 1);
+
+        let mut merged = (start, end);
+        if first < last {
+            merged.0 = merged.0.min(self.ranges[first].0);
+            merged.1 = merged.1.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    // in-place union
+    pub fn merge(&mut self, other: &IntervalSet) {
+        for &(start, end) in other.ranges.iter() {
+            self.insert(start, end);
+        }
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        result.merge(other);
+        result
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = IntervalSet::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (s1, e1) = self.ranges[i];
+            let (s2, e2) = other.ranges[j];
+            result.insert(s1.max(s2), e1.min(e2));
+            if e1 < e2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        result
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = IntervalSet::new();
+        for &(start, end) in self.ranges.iter() {
+            let mut from = start;
+            for &(s, e) in other.ranges.iter().filter(|(s, e)| *e >= start && *s <= end) {
+                result.insert(from, s - 1);
+                from = e + 1;
+            }
+            result.insert(from, end);
+        }
+        result
+    }
+
+    // true if every element of other is in this set
+    pub fn contains_set(&self, other: &IntervalSet) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|&(_, e)| e < value);
+        index < self.ranges.len() && self.ranges[index].0 <= value
+    }
+
+    pub fn overlaps(&self, other: &IntervalSet) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    // ranges between min and max which are not in the set
+    pub fn gaps(&self) -> IntervalSet {
+        IntervalSet {
+            ranges: self.ranges.windows(2).map(|pair| (pair[0].1 + 1, pair[1].0 - 1)).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // number of integers in the set
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|(start, end)| (end - start + 1) as u64).sum()
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges = self.ranges.iter()
+            .map(|(start, end)| match start == end {
+                true => format!("{start}"),
+                false => format!("{start}-{end}"),
+            })
+            .collect::<Vec<String>>();
+        write!(f, "{}", ranges.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntervalSet;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        ranges.iter().for_each(|&(start, end)| set.insert(start, end));
+        set
+    }
+
+    #[test]
+    fn insert_merges_overlapping_ranges() {
+        assert_eq!(set(&[(1, 5), (3, 8)]).to_string(), "1-8");
+        assert_eq!(set(&[(3, 8), (1, 5)]).to_string(), "1-8");
+        assert_eq!(set(&[(1, 10), (3, 4)]).to_string(), "1-10");
+    }
+
+    #[test]
+    fn insert_merges_adjacent_ranges() {
+        assert_eq!(set(&[(1, 3), (4, 6)]).to_string(), "1-6");
+        assert_eq!(set(&[(4, 6), (1, 3)]).to_string(), "1-6");
+        assert_eq!(set(&[(1, 3), (5, 6)]).to_string(), "1-3,5-6");
+    }
+
+    #[test]
+    fn insert_bridges_several_ranges() {
+        assert_eq!(set(&[(1, 2), (5, 6), (9, 10), (20, 21), (3, 9)]).to_string(), "1-10,20-21");
+    }
+
+    #[test]
+    fn inverted_range_is_empty() {
+        assert!(IntervalSet::from_range(5, 4).is_empty());
+        assert_eq!(set(&[(1, 2), (5, 4)]).to_string(), "1-2");
+    }
+
+    #[test]
+    fn intersection() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (15, 20)]);
+        assert_eq!(a.intersection(&b).to_string(), "4-5,10-11,15");
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+    }
+
+    #[test]
+    fn difference() {
+        let a = set(&[(5, 10)]);
+        // subtrahend starting before the range
+        assert_eq!(a.difference(&set(&[(1, 6)])).to_string(), "7-10");
+        assert_eq!(a.difference(&set(&[(1, 20)])).to_string(), "");
+        assert_eq!(a.difference(&set(&[(7, 8)])).to_string(), "5-6,9-10");
+        assert_eq!(a.difference(&set(&[(1, 3), (10, 12)])).to_string(), "5-9");
+    }
+
+    #[test]
+    fn contains_set() {
+        let a = set(&[(2, 8)]);
+        assert!(a.contains_set(&a.clone()));
+        assert!(a.contains_set(&set(&[(3, 4), (6, 8)])));
+        assert!(!a.contains_set(&set(&[(1, 4)])));
+        assert!(a.contains_set(&IntervalSet::new()));
+    }
+
+    #[test]
+    fn contains_and_overlaps() {
+        let a = set(&[(1, 3), (7, 9)]);
+        assert!(a.contains(1) && a.contains(3) && a.contains(8));
+        assert!(!a.contains(0) && !a.contains(5) && !a.contains(10));
+        assert!(a.overlaps(&set(&[(3, 5)])));
+        assert!(!a.overlaps(&set(&[(4, 6)])));
+    }
+
+    #[test]
+    fn gaps_and_len() {
+        let a = set(&[(1, 3), (7, 9), (11, 11)]);
+        assert_eq!(a.gaps().to_string(), "4-6,10");
+        assert_eq!(a.len(), 7);
+        assert!(IntervalSet::from_range(1, 5).gaps().is_empty());
+    }
+}
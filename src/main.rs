@@ -1,59 +1,56 @@
 extern crate core;
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
+mod answers;
+mod cli;
+mod input;
+mod interval;
+mod mem;
+mod scaffold;
+mod stats;
+mod topk;
+mod y2022;
 use std::env;
-use day01::day01;
-use day02::day02;
-use day03::day03;
-use day04::day04;
-use day05::day05;
-use day06::day06;
-use day07::day07;
-use day08::day08;
-use day09::day09;
-use day10::day10;
-use day11::day11;
-use day12::day12;
-use day13::day13;
-use day14::day14;
-use day15::day15;
-use day16::day16;
+
+// every day exposes a `pub fn dayNN()` which reads its own input and prints the answers
+pub type Solver = fn();
+
+// solvers per year, each year module keeps its own list of days
+// `cargo run new YYYY NN` adds new years to this list
+const YEARS: &[(&str, &[(&str, Solver)])] = &[
+    ("2022", y2022::SOLVERS),
+];
+
+fn find_solver(year: &str, day: &str) -> Option<Solver> {
+    YEARS.iter()
+        .find(|(key, _)| *key == year)
+        .and_then(|(_, days)| days.iter().find(|(key, _)| *key == day))
+        .map(|(_, solver)| *solver)
+}
 
 fn main() {
     let args: Vec<String> = env::args().collect();
+    let positional: Vec<&str> = args.iter()
+        .skip(1)
+        .filter(|it| !it.starts_with("--"))
+        .map(String::as_str)
+        .collect();
 
-    match args[1].as_str() {
-        "01" => day01(),
-        "02" => day02(),
-        "03" => day03(),
-        "04" => day04(),
-        "05" => day05(),
-        "06" => day06(),
-        "07" => day07(),
-        "08" => day08(),
-        "09" => day09(),
-        "10" => day10(),
-        "11" => day11(),
-        "12" => day12(),
-        "13" => day13(),
-        "14" => day14(),
-        "15" => day15(),
-        "16" => day16(),
-        other => println!("Invalid day number {other} as first parameter")
+    match positional.as_slice() {
+        ["new", year, day] => scaffold::new_day(year, day),
+        ["new", ..] => println!("Usage: new <year> <day number>"),
+        ["record", year, day, rest @ ..] => answers::record(year, day, rest),
+        ["answers", year, day] => answers::print(year, day),
+        [year, day, ..] => match find_solver(year, day) {
+            Some(solver) => {
+                answers::set_current_day(year, day);
+                if cli::has_flag("--mem") {
+                    mem::enable();
+                }
+                solver();
+                mem::report_remaining();
+            }
+            None => println!("No solver for year {year}, day {day}"),
+        },
+        _ => println!("Usage: <year> <day number> [--mem], e.g. 2022 04"),
     }
 }
@@ -0,0 +1,97 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+// counting wrapper around the system allocator, only counts while enabled via `--mem`
+struct CountingAllocator;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+// bytes currently allocated
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+// statistics of the section since the last report
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+static TOTAL_BYTES: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+// number of sections reported so far
+static SECTIONS: AtomicUsize = AtomicUsize::new(0);
+
+fn record_alloc(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+        TOTAL_BYTES.fetch_add(size, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn record_dealloc(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        // memory allocated before counting started may be freed while counting
+        let _ = CURRENT_BYTES.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+            Some(current.saturating_sub(size))
+        });
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    // a reallocation counts as one allocation of the new size
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/*
+ * Prints peak bytes, total allocated bytes and allocation count since the previous report
+ * and starts a new section. Days call this after finishing part 1, the runner reports the
+ * remainder once the day returns. Does nothing unless `--mem` was given.
+ */
+pub fn report(label: &str) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let peak = PEAK_BYTES.swap(CURRENT_BYTES.load(Ordering::Relaxed), Ordering::Relaxed);
+    let total = TOTAL_BYTES.swap(0, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.swap(0, Ordering::Relaxed);
+    SECTIONS.fetch_add(1, Ordering::Relaxed);
+    println!("[mem] {label}: peak {peak} bytes, {total} bytes allocated in {allocations} allocations");
+}
+
+// reports whatever the day allocated after its last report
+pub fn report_remaining() {
+    match SECTIONS.load(Ordering::Relaxed) {
+        0 => report("day"),
+        _ => report("part 2"),
+    }
+}
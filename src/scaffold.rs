@@ -0,0 +1,124 @@
+use std::fs;
+use std::path::Path;
+
+const MAIN_RS: &str = "src/main.rs";
+
+// `__YEAR__` and `__DAY__` are replaced by the year and the two-digit day number
+const YEAR_TEMPLATE: &str = r#"use crate::Solver;
+
+pub mod day__DAY__;
+
+// `cargo run new __YEAR__ NN` appends new days to this list
+pub const SOLVERS: &[(&str, Solver)] = &[
+    ("__DAY__", day__DAY__::day__DAY__),
+];
+"#;
+
+const TEMPLATE: &str = r#"use std::fs;
+use crate::answers;
+
+pub fn day__DAY__() {
+    println!("starting day __DAY__");
+
+    let contents = fs::read_to_string("data/__YEAR__/__DAY___input.txt").expect("Could not read file");
+
+    let (part1, part2) = solve(&contents);
+    println!("Part 1: {part1}");
+    answers::check(1, part1);
+    println!("Part 2: {part2}");
+    answers::check(2, part2);
+}
+
+fn solve(contents: &str) -> (usize, usize) {
+    let _lines = contents.split('\n');
+
+    (0, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::solve;
+    use std::fs;
+
+    #[test]
+    #[ignore = "expected demo answers not filled in yet"]
+    fn demo_input() {
+        let contents = fs::read_to_string("data/__YEAR__/__DAY___demo.txt").expect("Could not read file");
+        assert_eq!(solve(&contents), (0, 0));
+    }
+}
+"#;
+
+/*
+ * Creates `src/yYYYY/dayNN.rs` from the template, empty input files under `data/YYYY/` and
+ * registers the new module in the year's `mod.rs` (mod declaration and solver list entry).
+ * A year seen for the first time also gets its module registered in `main.rs`.
+ */
+pub fn new_day(year_arg: &str, day_arg: &str) {
+    let year = match year_arg.parse::<u32>() {
+        Ok(number) if number >= 2015 => number.to_string(),
+        _ => {
+            println!("Invalid year {year_arg}, expected 2015 or later");
+            return;
+        }
+    };
+    let day = match day_arg.parse::<u32>() {
+        Ok(number) if (1..=25).contains(&number) => format!("{:02}", number),
+        _ => {
+            println!("Invalid day number {day_arg}, expected 1 to 25");
+            return;
+        }
+    };
+
+    let module_path = format!("src/y{year}/day{day}.rs");
+    if Path::new(&module_path).exists() {
+        println!("{module_path} already exists, not overwriting it");
+        return;
+    }
+
+    let year_mod_path = format!("src/y{year}/mod.rs");
+    if Path::new(&year_mod_path).exists() {
+        let year_mod = fs::read_to_string(&year_mod_path).expect("Could not read year module");
+        let year_mod = insert_after_last(&year_mod, "pub mod day", &format!("pub mod day{day};"));
+        let year_mod = insert_after_last(&year_mod, "    (\"", &format!("    (\"{day}\", day{day}::day{day}),"));
+        fs::write(&year_mod_path, year_mod).expect("Could not write year module");
+    } else {
+        let main_rs = fs::read_to_string(MAIN_RS).expect("Could not read main.rs");
+        let main_rs = insert_after_last(&main_rs, "mod y", &format!("mod y{year};"));
+        let main_rs = insert_after_last(&main_rs, "    (\"", &format!("    (\"{year}\", y{year}::SOLVERS),"));
+
+        fs::create_dir_all(format!("src/y{year}")).expect("Could not create year directory");
+        fs::write(&year_mod_path, fill_template(YEAR_TEMPLATE, &year, &day)).expect("Could not write year module");
+        fs::write(MAIN_RS, main_rs).expect("Could not write main.rs");
+        println!("Registered year {year} in {MAIN_RS}");
+    }
+
+    fs::write(&module_path, fill_template(TEMPLATE, &year, &day)).expect("Could not write module");
+    println!("Created {module_path} and registered it in {year_mod_path}");
+
+    fs::create_dir_all(format!("data/{year}")).expect("Could not create data directory");
+    for data_file in [format!("data/{year}/{day}_demo.txt"), format!("data/{year}/{day}_input.txt")] {
+        if !Path::new(&data_file).exists() {
+            fs::write(&data_file, "").expect("Could not write data file");
+            println!("Created {data_file}");
+        }
+    }
+}
+
+fn fill_template(template: &str, year: &str, day: &str) -> String {
+    template.replace("__YEAR__", year).replace("__DAY__", day)
+}
+
+// inserts new_line after the last line starting with prefix
+fn insert_after_last(source: &str, prefix: &str, new_line: &str) -> String {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let last_match = lines
+        .iter()
+        .rposition(|line| line.starts_with(prefix))
+        .unwrap_or_else(|| panic!("No line starting with {prefix:?} to insert {new_line:?} after"));
+    lines.insert(last_match + 1, new_line);
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    result
+}
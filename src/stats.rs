@@ -0,0 +1,52 @@
+/*
+ * Descriptive statistics shared by the reports of several days, e.g. the calories per elf
+ * (day 01) or simulated tournament scores (day 02). All functions expect ascending values.
+ */
+
+// nearest-rank percentile, sorted must not be empty
+pub fn percentile<T: Copy>(sorted: &[T], p: usize) -> T {
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+// one line per bucket of equal width between min and max, bars are scaled to at most 50 characters
+pub fn print_histogram<T: Copy + Into<i64>>(sorted: &[T], buckets: usize) {
+    let (min, max) = match (sorted.first(), sorted.last()) {
+        (Some(&min), Some(&max)) => (min.into(), max.into()),
+        _ => return,
+    };
+    let width = (max - min) / buckets as i64 + 1;
+
+    let mut counts = vec![0; buckets];
+    for &value in sorted {
+        counts[((value.into() - min) / width) as usize] += 1;
+    }
+
+    let max_count = *counts.iter().max().unwrap();
+    for (i, count) in counts.iter().enumerate() {
+        let from = min + i as i64 * width;
+        let bar = "#".repeat(count * 50 / max_count);
+        println!("{:>7} - {:>7} | {:<50} {count}", from, from + width - 1, bar);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::percentile;
+
+    #[test]
+    fn nearest_rank() {
+        let sorted = [15, 20, 35, 40, 50];
+        assert_eq!(percentile(&sorted, 5), 15);
+        assert_eq!(percentile(&sorted, 30), 20);
+        assert_eq!(percentile(&sorted, 40), 20);
+        assert_eq!(percentile(&sorted, 50), 35);
+        assert_eq!(percentile(&sorted, 100), 50);
+    }
+
+    #[test]
+    fn single_value() {
+        assert_eq!(percentile(&[7], 0), 7);
+        assert_eq!(percentile(&[7], 99), 7);
+    }
+}
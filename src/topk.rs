@@ -0,0 +1,73 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/*
+ * Keeps the k largest items pushed so far in a min-heap of size k, so ranking a stream of
+ * n items needs O(k) memory and O(n log k) time instead of collecting and sorting everything.
+ */
+pub struct TopK<T: Ord> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> TopK<T> {
+        TopK { k, heap: BinaryHeap::with_capacity(k + 1) }
+    }
+
+    pub fn push(&mut self, item: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(item));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if item > smallest.0 {
+                *smallest = Reverse(item);
+            }
+        }
+    }
+
+    // largest item first
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        // ascending order of Reverse is descending order of the items
+        self.heap.into_sorted_vec().into_iter().map(|it| it.0).collect()
+    }
+}
+
+// the k largest items of an iterator, largest first
+pub fn largest<T: Ord>(items: impl IntoIterator<Item = T>, k: usize) -> Vec<T> {
+    let mut top = TopK::new(k);
+    items.into_iter().for_each(|it| top.push(it));
+    top.into_sorted_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{largest, TopK};
+    use std::cmp::Reverse;
+
+    #[test]
+    fn keeps_the_largest_items_largest_first() {
+        assert_eq!(largest([3, 1, 4, 1, 5, 9, 2, 6], 3), vec![9, 6, 5]);
+    }
+
+    #[test]
+    fn fewer_items_than_k() {
+        assert_eq!(largest([2, 7], 5), vec![7, 2]);
+    }
+
+    #[test]
+    fn k_zero_keeps_nothing() {
+        assert_eq!(largest([1, 2, 3], 0), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn ties() {
+        assert_eq!(largest([5, 5, 5, 1], 2), vec![5, 5]);
+
+        // with the position as tie breaker the earlier item wins
+        let mut top = TopK::new(2);
+        for (id, value) in [(1, 10), (2, 20), (3, 20), (4, 10)] {
+            top.push((value, Reverse(id)));
+        }
+        assert_eq!(top.into_sorted_vec(), vec![(20, Reverse(2)), (20, Reverse(3))]);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::answers;
+use crate::cli;
+use crate::input;
+use crate::mem;
+use crate::stats;
+use crate::topk::TopK;
+use itertools::Itertools;
+use std::cmp::Reverse;
+
+#[derive(Debug, Clone, Copy)]
+struct Elf {
+    // 1-based position in the input
+    id: usize,
+    calories: i32,
+    items: usize,
+}
+
+// calls on_elf for every elf in the input without keeping the elves in memory, elves are
+// separated by one or more empty lines
+fn for_each_elf(lines: impl Iterator<Item = String>, mut on_elf: impl FnMut(Elf)) {
+    let mut elf = Elf { id: 1, calories: 0, items: 0 };
+
+    for line in lines {
+        match line.trim() {
+            "" if elf.items == 0 => {}
+            "" => {
+                on_elf(elf);
+                elf = Elf { id: elf.id + 1, calories: 0, items: 0 };
+            }
+            line => {
+                let calories: i32 = line.parse().expect("Must be a number");
+                elf.calories += calories;
+                elf.items += 1;
+            }
+        }
+    }
+    // last elf is not followed by an empty line
+    if elf.items > 0 {
+        on_elf(elf);
+    }
+}
+
+pub fn day01() {
+    println!("starting day01");
+
+    let path = "data/2022/01_elves_calories.txt";
+
+    let mut calories_by_elf = TopK::new(3);
+    for_each_elf(input::lines(path), |elf| calories_by_elf.push(elf.calories));
+    let top3 = calories_by_elf.into_sorted_vec();
+
+    match top3.first() {
+        Some(value) => {
+            println!("Maximum calories carried by one elf {value}");
+            answers::check(1, value);
+        }
+        None => println!("Could not determine calories by elf")
+    }
+    mem::report("part 1");
+
+    let top3 : i32 = top3.iter().sum();
+    println!("Top3 elves {:?}", top3);
+    answers::check(2, top3);
+
+    if cli::has_flag("--report") {
+        let mut elves = vec![];
+        for_each_elf(input::lines(path), |elf| elves.push(elf));
+        print_report(&elves);
+    }
+    if let Some(n) = cli::parsed_option::<usize>("--top") {
+        print_top_n(path, n);
+    }
+}
+
+fn print_top_n(path: &str, n: usize) {
+    // lower elf id wins ties
+    let mut top = TopK::new(n);
+    for_each_elf(input::lines(path), |elf| top.push((elf.calories, Reverse(elf.id), elf.items)));
+
+    println!("Top {n} elves:");
+    for (calories, Reverse(id), items) in top.into_sorted_vec() {
+        println!("  elf {:>5}: {:>7} calories in {:>3} items", id, calories, items);
+    }
+}
+
+fn print_report(elves: &[Elf]) {
+    if elves.is_empty() {
+        println!("No elves in input");
+        return;
+    }
+
+    println!("  elf   calories  items");
+    for elf in elves {
+        println!("{:>5} {:>10} {:>6}", elf.id, elf.calories, elf.items);
+    }
+
+    let sorted = elves.iter().map(|it| it.calories).sorted().collect::<Vec<i32>>();
+    let count = sorted.len();
+    let total_items: usize = elves.iter().map(|it| it.items).sum();
+    let mean = sorted.iter().map(|&it| it as f64).sum::<f64>() / count as f64;
+    let median = match count % 2 {
+        0 => (sorted[count / 2 - 1] as f64 + sorted[count / 2] as f64) / 2.0,
+        _ => sorted[count / 2] as f64,
+    };
+
+    println!("{count} elves carrying {total_items} items");
+    println!("min {}, max {}, mean {:.1}, median {:.1}", sorted[0], sorted[count - 1], mean, median);
+    for p in [10, 25, 50, 75, 90, 99] {
+        println!("p{p}: {}", stats::percentile(&sorted, p));
+    }
+
+    stats::print_histogram(&sorted, 10);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{for_each_elf, Elf};
+
+    fn elves(input: &str) -> Vec<(usize, i32, usize)> {
+        let mut elves = vec![];
+        for_each_elf(input.split('\n').map(String::from), |elf: Elf| elves.push((elf.id, elf.calories, elf.items)));
+        elves
+    }
+
+    #[test]
+    fn last_elf_without_trailing_empty_line() {
+        assert_eq!(elves("1000\n2000\n\n4000"), vec![(1, 3000, 2), (2, 4000, 1)]);
+        assert_eq!(elves("1000\n2000\n\n4000\n"), vec![(1, 3000, 2), (2, 4000, 1)]);
+    }
+
+    #[test]
+    fn consecutive_empty_lines_separate_elves_once() {
+        assert_eq!(elves("\n1000\n\n\n\n2000\n\n"), vec![(1, 1000, 1), (2, 2000, 1)]);
+    }
+
+    #[test]
+    fn no_elves() {
+        assert_eq!(elves(""), vec![]);
+        assert_eq!(elves("\n\n"), vec![]);
+    }
+}
@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+use crate::answers;
+use crate::cli;
+use crate::input;
+use crate::mem;
+use crate::stats;
+use itertools::Itertools;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Outcome {
+    Loss = 0,
+    Draw = 1,
+    Win = 2,
+}
+
+/*
+ * Cyclic dominance game with an odd number of shapes: listed in cycle order, every shape beats
+ * the (n-1)/2 shapes before it and loses against the (n-1)/2 shapes after it.
+ * Rock-paper-scissors is the smallest such game, rock-spock-paper-lizard-scissors the next one.
+ */
+struct Game {
+    names: Vec<&'static str>,
+    shape_scores: Vec<u32>,
+    // indexed by Outcome
+    outcome_scores: [u32; 3],
+}
+
+impl Game {
+    // shapes with their scores, in cycle order
+    fn new(shapes: &[(&'static str, u32)], outcome_scores: [u32; 3]) -> Game {
+        assert!(shapes.len() % 2 == 1, "A cyclic game needs an odd number of shapes");
+        Game {
+            names: shapes.iter().map(|(name, _)| *name).collect(),
+            shape_scores: shapes.iter().map(|(_, score)| *score).collect(),
+            outcome_scores,
+        }
+    }
+
+    fn rock_paper_scissors() -> Game {
+        Game::new(&[("rock", 1), ("paper", 2), ("scissors", 3)], [0, 3, 6])
+    }
+
+    fn rock_paper_scissors_lizard_spock() -> Game {
+        Game::new(&[("rock", 1), ("spock", 5), ("paper", 2), ("lizard", 4), ("scissors", 3)], [0, 3, 6])
+    }
+
+    fn shape(&self, name: &str) -> usize {
+        self.names.iter()
+            .position(|&it| it == name)
+            .unwrap_or_else(|| panic!("Unknown shape {name}"))
+    }
+
+    fn outcome(&self, mine: usize, theirs: usize) -> Outcome {
+        let n = self.names.len();
+        match (mine + n - theirs) % n {
+            0 => Outcome::Draw,
+            distance if distance <= n / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    // with more than three shapes, the neighbour in the cycle is picked among the candidates
+    fn shape_for_outcome(&self, theirs: usize, outcome: Outcome) -> usize {
+        let n = self.names.len();
+        match outcome {
+            Outcome::Loss => (theirs + n - 1) % n,
+            Outcome::Draw => theirs,
+            Outcome::Win => (theirs + 1) % n,
+        }
+    }
+
+    fn score(&self, mine: usize, theirs: usize) -> u32 {
+        self.shape_scores[mine] + self.outcome_scores[self.outcome(mine, theirs) as usize]
+    }
+}
+
+// symbols of a strategy guide column and what they stand for
+struct Alphabet<T> {
+    symbols: HashMap<String, T>,
+}
+
+impl<T: Copy> Alphabet<T> {
+    fn new(symbols: &[(&str, T)]) -> Alphabet<T> {
+        Alphabet {
+            symbols: symbols.iter().map(|(symbol, it)| (symbol.to_string(), *it)).collect(),
+        }
+    }
+
+    fn get(&self, symbol: &str) -> T {
+        *self.symbols.get(symbol).unwrap_or_else(|| panic!("Unknown symbol {symbol}"))
+    }
+}
+
+fn shape_alphabet(game: &Game, symbols: &[(&str, &str)]) -> Alphabet<usize> {
+    let shapes = symbols.iter()
+        .map(|(symbol, name)| (*symbol, game.shape(name)))
+        .collect::<Vec<(&str, usize)>>();
+    Alphabet::new(&shapes)
+}
+
+// how to read the second column of the strategy guide
+trait Interpretation {
+    fn describe(&self, game: &Game) -> String;
+    fn my_shape(&self, game: &Game, theirs: usize, symbol: &str) -> usize;
+}
+
+// part 1: the symbol is the shape to play
+struct ShapeMapping(Alphabet<usize>);
+
+impl Interpretation for ShapeMapping {
+    fn describe(&self, game: &Game) -> String {
+        let mapping = self.0.symbols.iter()
+            .sorted_by_key(|(symbol, _)| *symbol)
+            .map(|(symbol, &shape)| format!("{symbol}={}", game.names[shape]))
+            .join(", ");
+        format!("play shape ({mapping})")
+    }
+
+    fn my_shape(&self, _game: &Game, _theirs: usize, symbol: &str) -> usize {
+        self.0.get(symbol)
+    }
+}
+
+// part 2: the symbol is the outcome to achieve
+struct DesiredOutcome(Alphabet<Outcome>);
+
+impl Interpretation for DesiredOutcome {
+    fn describe(&self, _game: &Game) -> String {
+        let mapping = self.0.symbols.iter()
+            .sorted_by_key(|(symbol, _)| *symbol)
+            .map(|(symbol, outcome)| format!("{symbol}={:?}", outcome))
+            .join(", ");
+        format!("reach outcome ({mapping})")
+    }
+
+    fn my_shape(&self, game: &Game, theirs: usize, symbol: &str) -> usize {
+        game.shape_for_outcome(theirs, self.0.get(symbol))
+    }
+}
+
+// explicit shape for every combination of opponent shape and symbol
+struct CustomTable(HashMap<(usize, String), usize>);
+
+impl CustomTable {
+    // spec like "AX=paper,AY=rock,...", the first character is the opponent symbol
+    fn parse(game: &Game, opponent: &Alphabet<usize>, spec: &str) -> CustomTable {
+        let mut table = HashMap::new();
+        for entry in spec.split(',') {
+            let (key, shape) = entry.trim()
+                .split_once('=')
+                .unwrap_or_else(|| panic!("Expected <opponent><symbol>=<shape>, got {entry}"));
+            let (opponent_symbol, symbol) = key.split_at(1);
+            table.insert((opponent.get(opponent_symbol), symbol.to_string()), game.shape(shape));
+        }
+        CustomTable(table)
+    }
+}
+
+impl Interpretation for CustomTable {
+    fn describe(&self, _game: &Game) -> String {
+        format!("custom table with {} entries", self.0.len())
+    }
+
+    fn my_shape(&self, game: &Game, theirs: usize, symbol: &str) -> usize {
+        *self.0.get(&(theirs, symbol.to_string()))
+            .unwrap_or_else(|| panic!("No table entry for {} and {symbol}", game.names[theirs]))
+    }
+}
+
+// the guide reduced to how often each (opponent shape, symbol) round occurs
+struct StrategyGuide {
+    round_counts: HashMap<(usize, String), u32>,
+}
+
+// opponent shape and own symbol of every round, in order
+fn rounds<'a>(path: &str, opponent: &'a Alphabet<usize>) -> impl Iterator<Item = (usize, String)> + 'a {
+    input::lines(path).filter_map(move |line| {
+        let mut tokens = line.split_whitespace();
+        match (tokens.next(), tokens.next()) {
+            (Some(opponent_token), Some(my_token)) => Some((opponent.get(opponent_token), my_token.to_string())),
+            _ => None,
+        }
+    })
+}
+
+impl StrategyGuide {
+    fn read(path: &str, opponent: &Alphabet<usize>) -> StrategyGuide {
+        let mut round_counts = HashMap::new();
+        for round in rounds(path, opponent) {
+            *round_counts.entry(round).or_insert(0) += 1;
+        }
+        StrategyGuide { round_counts }
+    }
+
+    fn symbols(&self) -> Vec<&str> {
+        self.round_counts.keys().map(|(_, symbol)| symbol.as_str()).unique().sorted().collect()
+    }
+
+    fn total_score(&self, game: &Game, interpretation: &dyn Interpretation) -> u32 {
+        self.round_counts.iter()
+            .map(|((theirs, symbol), count)| {
+                let mine = interpretation.my_shape(game, *theirs, symbol);
+                count * game.score(mine, *theirs)
+            })
+            .sum()
+    }
+}
+
+fn print_breakdown(path: &str, game: &Game, opponent: &Alphabet<usize>, interpretation: &dyn Interpretation) {
+    println!("Breakdown when following the guide as {}:", interpretation.describe(game));
+    let mut outcome_counts = [0; 3];
+    let mut total = 0;
+    for (round, (theirs, symbol)) in rounds(path, opponent).enumerate() {
+        let mine = interpretation.my_shape(game, theirs, &symbol);
+        let outcome = game.outcome(mine, theirs);
+        let score = game.score(mine, theirs);
+        outcome_counts[outcome as usize] += 1;
+        total += score;
+        println!("{:>5}: {:>8} vs {:>8} -> {:?}, score {score}", round + 1, game.names[theirs], game.names[mine], outcome);
+    }
+    println!("{} wins, {} draws, {} losses, total score {total}", outcome_counts[2], outcome_counts[1], outcome_counts[0]);
+}
+
+// splitmix64, good enough for simulations and reproducible from a seed
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/*
+ * Plays the guide's own column against opponents choosing their shapes uniformly at random and
+ * returns the total score of every tournament. Only the number of rounds per symbol matters,
+ * as the opponent's shapes of the guide are replaced.
+ */
+fn simulate(game: &Game, guide: &StrategyGuide, interpretation: &dyn Interpretation, tournaments: usize, seed: u64) -> Vec<u32> {
+    let mut rounds_per_symbol: HashMap<&str, u32> = HashMap::new();
+    for ((_, symbol), count) in guide.round_counts.iter() {
+        *rounds_per_symbol.entry(symbol.as_str()).or_insert(0) += count;
+    }
+    let rounds_per_symbol = rounds_per_symbol.into_iter().sorted().collect::<Vec<(&str, u32)>>();
+
+    let mut rng = Rng(seed);
+    (0..tournaments)
+        .map(|_| {
+            let mut total = 0;
+            for (symbol, count) in rounds_per_symbol.iter() {
+                for _ in 0..*count {
+                    let theirs = rng.below(game.names.len());
+                    total += game.score(interpretation.my_shape(game, theirs, symbol), theirs);
+                }
+            }
+            total
+        })
+        .collect()
+}
+
+fn print_distribution(scores: &[u32]) {
+    if scores.is_empty() {
+        println!("No tournaments simulated");
+        return;
+    }
+    let sorted = scores.iter().copied().sorted().collect::<Vec<u32>>();
+    let count = sorted.len() as f64;
+    let mean = sorted.iter().map(|&it| it as f64).sum::<f64>() / count;
+    let variance = sorted.iter().map(|&it| (it as f64 - mean).powi(2)).sum::<f64>() / count;
+    let percentile = |p: usize| stats::percentile(&sorted, p);
+
+    println!("{} tournaments: min {}, max {}, mean {:.1}, standard deviation {:.1}",
+             sorted.len(), sorted[0], sorted[sorted.len() - 1], mean, variance.sqrt());
+    println!("p5 {}, p25 {}, median {}, p75 {}, p95 {}",
+             percentile(5), percentile(25), percentile(50), percentile(75), percentile(95));
+    stats::print_histogram(&sorted, 10);
+}
+
+/*
+ * Tries every assignment of the guide's symbols to shapes and to outcomes and returns the
+ * interpretation with the best total score (highest or lowest). Outcome assignments have to use
+ * as many different outcomes as possible, one per symbol for up to three symbols and every
+ * outcome with more symbols, otherwise "always win" would trivially be the best.
+ */
+fn optimize(game: &Game, guide: &StrategyGuide, maximize: bool) -> (Box<dyn Interpretation>, u32) {
+    let symbols = guide.symbols();
+    let outcomes = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    let shape_mappings = (0..symbols.len())
+        .map(|_| 0..game.names.len())
+        .multi_cartesian_product()
+        .map(|shapes| {
+            let mapping = symbols.iter().copied().zip(shapes).collect::<Vec<(&str, usize)>>();
+            Box::new(ShapeMapping(Alphabet::new(&mapping))) as Box<dyn Interpretation>
+        });
+    let distinct_outcomes = symbols.len().min(outcomes.len());
+    let outcome_mappings = (0..symbols.len())
+        .map(|_| outcomes.iter().copied())
+        .multi_cartesian_product()
+        .filter(|chosen| chosen.iter().map(|&outcome| outcome as usize).unique().count() == distinct_outcomes)
+        .map(|chosen| {
+            let mapping = symbols.iter().copied().zip(chosen).collect::<Vec<(&str, Outcome)>>();
+            Box::new(DesiredOutcome(Alphabet::new(&mapping))) as Box<dyn Interpretation>
+        });
+
+    let scored = shape_mappings.chain(outcome_mappings)
+        .map(|interpretation| {
+            let score = guide.total_score(game, interpretation.as_ref());
+            (interpretation, score)
+        });
+    let best = match maximize {
+        true => scored.max_by_key(|(_, score)| *score),
+        false => scored.min_by_key(|(_, score)| *score),
+    };
+    best.expect("Strategy guide is empty")
+}
+
+pub fn day02() {
+    println!("starting day 02");
+
+    let (game, opponent, mine) = match cli::option_value("--game").as_deref() {
+        Some("rpsls") => {
+            let game = Game::rock_paper_scissors_lizard_spock();
+            let opponent = shape_alphabet(&game, &[("A", "rock"), ("B", "paper"), ("C", "scissors"), ("D", "lizard"), ("E", "spock")]);
+            let mine = shape_alphabet(&game, &[("X", "rock"), ("Y", "paper"), ("Z", "scissors"), ("U", "lizard"), ("V", "spock")]);
+            (game, opponent, mine)
+        }
+        _ => {
+            let game = Game::rock_paper_scissors();
+            let opponent = shape_alphabet(&game, &[("A", "rock"), ("B", "paper"), ("C", "scissors")]);
+            let mine = shape_alphabet(&game, &[("X", "rock"), ("Y", "paper"), ("Z", "scissors")]);
+            (game, opponent, mine)
+        }
+    };
+    let outcomes = Alphabet::new(&[("X", Outcome::Loss), ("Y", Outcome::Draw), ("Z", Outcome::Win)]);
+
+    let path = "data/2022/02_rockpaperscissors_strategy_guide.txt";
+    let guide = StrategyGuide::read(path, &opponent);
+
+    let part1 = ShapeMapping(mine);
+    let part2 = DesiredOutcome(outcomes);
+    let accu_part1 = guide.total_score(&game, &part1);
+    println!("score part1 {:?}", accu_part1);
+    answers::check(1, accu_part1);
+    mem::report("part 1");
+    let accu_part2 = guide.total_score(&game, &part2);
+    println!("score part2 {:?}", accu_part2);
+    answers::check(2, accu_part2);
+
+    if let Some(spec) = cli::option_value("--table") {
+        let table = CustomTable::parse(&game, &opponent, &spec);
+        println!("score with {}: {}", table.describe(&game), guide.total_score(&game, &table));
+    }
+
+    if let Some(goal) = cli::option_value("--optimize") {
+        let (best, score) = optimize(&game, &guide, goal != "min");
+        println!("best interpretation ({goal}): {} with score {score}", best.describe(&game));
+    }
+
+    // breakdown and simulation follow the guide as in part 1 unless `--part 2` is given
+    let interpretation: &dyn Interpretation = match cli::parsed_option::<u8>("--part") {
+        Some(2) => &part2,
+        _ => &part1,
+    };
+    if cli::has_flag("--breakdown") {
+        print_breakdown(path, &game, &opponent, interpretation);
+    }
+    if let Some(tournaments) = cli::parsed_option::<usize>("--simulate") {
+        let seed = cli::parsed_option::<u64>("--seed").unwrap_or(2022);
+        println!("Simulating {} against random opponents (seed {seed})", interpretation.describe(&game));
+        print_distribution(&simulate(&game, &guide, interpretation, tournaments, seed));
+    }
+}
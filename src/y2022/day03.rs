@@ -0,0 +1,247 @@
+use itertools::Itertools;
+use crate::answers;
+use crate::cli;
+use crate::input;
+use crate::mem;
+
+fn char_to_value(value: char) -> u32 {
+    match value  {
+        'a'..='z' => value as u32 - 'a' as u32 + 1,
+        'A'..='Z' => value as u32 - 'A' as u32 + 27,
+        _other => 0
+    }
+}
+
+fn value_to_char(value: u32) -> char {
+    match value {
+        1..=26 => (b'a' + (value - 1) as u8) as char,
+        _ => (b'A' + (value - 27) as u8) as char,
+    }
+}
+
+// set of items as bitmask, bit n is set if the item with priority n is present
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Items(u64);
+
+impl Items {
+    const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    fn from_str(items: &str) -> Items {
+        Items(items.chars().fold(0, |mask, c| mask | 1 << char_to_value(c)))
+    }
+
+    fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |priority| self.0 & 1 << priority != 0)
+    }
+
+    fn priority_sum(self) -> u32 {
+        self.priorities().sum()
+    }
+
+    fn to_chars(self) -> String {
+        self.priorities().map(value_to_char).collect()
+    }
+}
+
+// items contained in every one of the equally sized compartments, None if the items cannot be split evenly
+fn shared_between_compartments(rucksack: &str, compartments: usize) -> Option<Items> {
+    if !rucksack.len().is_multiple_of(compartments) {
+        return None;
+    }
+    let size = rucksack.len() / compartments;
+    let shared = (0..compartments)
+        .map(|i| Items::from_str(&rucksack[i * size..(i + 1) * size]))
+        .fold(Items::ALL, Items::intersection);
+    Some(shared)
+}
+
+/*
+ * Minimal set of single item moves between the two compartments after which no item type is
+ * in both compartments and both still hold the same number of items. Every item type ends up
+ * completely in one compartment, a dynamic program over the size of the first compartment finds
+ * the cheapest choice. Returns (item, count, from, to) moves or None if there is no such
+ * arrangement, e.g. when one item type fills more than a compartment.
+ */
+fn repair_moves(rucksack: &str) -> Option<Vec<(char, usize, usize, usize)>> {
+    let half = rucksack.len() / 2;
+    let mut counts = [[0usize; 2]; 53];
+    for (i, c) in rucksack.chars().enumerate() {
+        counts[char_to_value(c) as usize][i / half] += 1;
+    }
+    let types = (1..=52).filter(|&t| counts[t][0] + counts[t][1] > 0).collect::<Vec<usize>>();
+
+    // cheapest[i][size]: minimal moves and whether type i-1 went into compartment 1,
+    // for the first i types with size items in compartment 1
+    let mut cheapest: Vec<Vec<Option<(usize, bool)>>> = vec![vec![None; rucksack.len() + 1]; types.len() + 1];
+    cheapest[0][0] = Some((0, false));
+    for (i, &t) in types.iter().enumerate() {
+        let [first, second] = counts[t];
+        for size in 0..=rucksack.len() {
+            if let Some((cost, _)) = cheapest[i][size] {
+                let options = [(size + first + second, cost + second, true), (size, cost + first, false)];
+                for (new_size, new_cost, into_first) in options {
+                    if new_size <= rucksack.len() && cheapest[i + 1][new_size].is_none_or(|(it, _)| new_cost < it) {
+                        cheapest[i + 1][new_size] = Some((new_cost, into_first));
+                    }
+                }
+            }
+        }
+    }
+    cheapest[types.len()][half]?;
+
+    // walk back through the table to find out where each type went
+    let mut moves = vec![];
+    let mut size = half;
+    for (i, &t) in types.iter().enumerate().rev() {
+        let [first, second] = counts[t];
+        let (_, into_first) = cheapest[i + 1][size].unwrap();
+        if into_first {
+            size -= first + second;
+            if second > 0 {
+                moves.push((value_to_char(t as u32), second, 2, 1));
+            }
+        } else if first > 0 {
+            moves.push((value_to_char(t as u32), first, 1, 2));
+        }
+    }
+    moves.reverse();
+    Some(moves)
+}
+
+// reports malformed rucksacks, repair moves for misplaced items and questionable badge groups
+fn validate(path: &str, group_size: usize) {
+    let mut problems = 0;
+    let mut misplaced = 0;
+    for (line_number, line) in input::lines(path).enumerate() {
+        let line_number = line_number + 1;
+        if line.is_empty() {
+            println!("line {line_number}: empty rucksack");
+            problems += 1;
+            continue;
+        }
+        if let Some(invalid) = line.chars().find(|c| !c.is_ascii_alphabetic()) {
+            println!("line {line_number}: invalid item {invalid:?}");
+            problems += 1;
+            continue;
+        }
+        if !line.len().is_multiple_of(2) {
+            println!("line {line_number}: odd number of items ({}), cannot be split into two compartments", line.len());
+            problems += 1;
+            continue;
+        }
+
+        // the number of items is even
+        let shared = shared_between_compartments(&line, 2).unwrap();
+        if shared.0 == 0 {
+            continue;
+        }
+        misplaced += 1;
+        let suggestion = match repair_moves(&line) {
+            Some(moves) => moves.iter()
+                .map(|(item, count, from, to)| format!("move {count}x {item} from compartment {from} to {to}"))
+                .join(", "),
+            None => String::from("no arrangement with disjoint compartments of equal size"),
+        };
+        println!("line {line_number}: {} in both compartments: {suggestion}", shared.to_chars());
+    }
+
+    for (group_number, group) in (&input::lines(path).chunks(group_size)).into_iter().enumerate() {
+        let group = group.collect::<Vec<String>>();
+        let first_line = group_number * group_size + 1;
+        if group.len() < group_size {
+            println!("lines {first_line}-{}: incomplete group of {} elves", first_line + group.len() - 1, group.len());
+            problems += 1;
+        }
+        let badges = group.iter()
+            .map(|rucksack| Items::from_str(rucksack))
+            .fold(Items::ALL, Items::intersection);
+        match badges.priorities().count() {
+            1 => {}
+            0 => {
+                println!("lines {first_line}-{}: no badge candidate", first_line + group.len() - 1);
+                problems += 1;
+            }
+            _ => {
+                println!("lines {first_line}-{}: multiple badge candidates {}", first_line + group.len() - 1, badges.to_chars());
+                problems += 1;
+            }
+        }
+    }
+    println!("{misplaced} rucksacks with items in both compartments, {problems} other problems found");
+}
+
+pub fn day03() {
+    println!("starting day 03");
+
+    let path = "data/2022/03_1_rucksack.txt";
+    let compartments = cli::parsed_option::<usize>("--compartments").unwrap_or(2);
+    let group_size = cli::parsed_option::<usize>("--group-size").unwrap_or(3);
+    if compartments == 0 || group_size == 0 {
+        println!("--compartments and --group-size must be at least 1");
+        return;
+    }
+    let list_items = cli::has_flag("--list");
+
+    if cli::has_flag("--validate") {
+        validate(path, group_size);
+        return;
+    }
+
+    let mut accu = 0;
+    let mut multiple_shared = 0;
+    let mut skipped = 0;
+    for (line_number, line) in input::lines(path).enumerate() {
+        let shared = match shared_between_compartments(&line, compartments) {
+            Some(shared) => shared,
+            None => {
+                println!("line {}: {} items cannot be split into {compartments} compartments, skipping", line_number + 1, line.len());
+                skipped += 1;
+                continue;
+            }
+        };
+        if shared.priorities().count() > 1 {
+            multiple_shared += 1;
+        }
+        if list_items {
+            println!("rucksack {}: {}", line_number + 1, shared.to_chars());
+        }
+        accu += shared.priority_sum();
+    }
+
+    println!("value of all duplicate rucksack items {accu}");
+    if multiple_shared > 0 {
+        println!("{multiple_shared} rucksacks with more than one item in all {compartments} compartments");
+    }
+    if skipped > 0 {
+        println!("{skipped} rucksacks skipped");
+    }
+    answers::check(1, accu);
+    mem::report("part 1");
+
+    let mut accu_part2 = 0;
+    let mut multiple_badges = 0;
+
+    for (group_number, group) in (&input::lines(path).chunks(group_size)).into_iter().enumerate() {
+        let badges = group
+            .map(|rucksack| Items::from_str(&rucksack))
+            .fold(Items::ALL, Items::intersection);
+        if badges.priorities().count() > 1 {
+            multiple_badges += 1;
+        }
+        if list_items {
+            println!("group {}: {}", group_number + 1, badges.to_chars());
+        }
+        accu_part2 += badges.priority_sum();
+    }
+
+    println!("value of all badges for {group_size}-tuples of elves {accu_part2}");
+    if multiple_badges > 0 {
+        println!("{multiple_badges} groups with more than one badge candidate");
+    }
+    answers::check(2, accu_part2);
+
+}
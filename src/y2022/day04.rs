@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::str::FromStr;
+use regex::{Captures, Regex};
+use lazy_static::lazy_static;
+use itertools::Itertools;
+use crate::answers;
+use crate::cli;
+use crate::interval::IntervalSet;
+
+fn capture_group_to_i64(cap: &Captures, name: &str) -> Result<i64, String> {
+    let value = cap.name(name).unwrap().as_str();
+    match i64::from_str(value) {
+        // the section after the last one has to fit as well
+        Ok(section) if section < i64::MAX => Ok(section),
+        _ => Err(format!("section {value} is too large")),
+    }
+}
+
+// true if any assignment of the group fully contains another one
+fn has_contained_assignment(group: &[IntervalSet]) -> bool {
+    group.iter().enumerate().any(|(i, a)| {
+        group.iter().enumerate().any(|(j, b)| i != j && a.contains_set(b))
+    })
+}
+
+// true if any two assignments of the group overlap
+fn has_overlapping_assignments(group: &[IntervalSet]) -> bool {
+    group.iter().enumerate().any(|(i, a)| {
+        group.iter().skip(i + 1).any(|b| a.overlaps(b))
+    })
+}
+
+// sections of constant coverage as (first section, last section, number of elves), computed
+// by sweeping over the points where coverage changes
+fn sweep(coverage_changes: &BTreeMap<i64, i64>) -> Vec<(i64, i64, i64)> {
+    let mut segments = vec![];
+    let mut coverage = 0;
+    for (pair_start, pair_end) in coverage_changes.iter().tuple_windows() {
+        coverage += pair_start.1;
+        segments.push((*pair_start.0, pair_end.0 - 1, coverage));
+    }
+    segments
+}
+
+fn print_sweep(coverage_changes: &BTreeMap<i64, i64>) {
+    let segments = sweep(coverage_changes);
+    let max_coverage = match segments.iter().map(|(_, _, coverage)| *coverage).max() {
+        Some(max) => max,
+        None => return,
+    };
+
+    let mut most_covered = IntervalSet::new();
+    let mut not_covered = IntervalSet::new();
+    let mut sections_by_coverage: BTreeMap<i64, u64> = BTreeMap::new();
+    for &(start, end, coverage) in segments.iter() {
+        if coverage == max_coverage {
+            most_covered.insert(start, end);
+        }
+        if coverage == 0 {
+            not_covered.insert(start, end);
+        }
+        *sections_by_coverage.entry(coverage).or_insert(0) += (end - start + 1) as u64;
+    }
+
+    println!("Sections covered by the most elves ({max_coverage}): {most_covered}");
+    match not_covered.is_empty() {
+        true => println!("Every section is covered by at least one elf"),
+        false => println!("Sections covered by nobody: {not_covered} ({} sections)", not_covered.len()),
+    }
+    println!("Number of sections by number of elves covering them:");
+    for (coverage, sections) in sections_by_coverage.iter() {
+        println!("{:>5} elves: {sections} sections", coverage);
+    }
+    println!("Coverage per section:");
+    for &(start, end, coverage) in segments.iter() {
+        let sections = match start == end {
+            true => format!("{start}"),
+            false => format!("{start}-{end}"),
+        };
+        // long bars are scaled down to 60 characters
+        let bar = "#".repeat((coverage * 60 / max_coverage.max(60)) as usize);
+        println!("{:>11} | {bar} {coverage}", sections);
+    }
+}
+
+lazy_static! {
+    static ref LINE: Regex = Regex::new(r"^\d+-\d+(,\d+-\d+)*$").unwrap();
+    static ref RANGE: Regex = Regex::new(r"(?P<start>\d+)-(?P<end>\d+)").unwrap();
+}
+
+// a line lists the assignments of a group of elves, usually a pair
+fn parse_line(line: &str) -> Result<Vec<(i64, i64)>, String> {
+    if !LINE.is_match(line.trim()) {
+        return Err(format!("expected assignments like 2-4,6-8, got {line:?}"));
+    }
+    let ranges = RANGE.captures_iter(line)
+        .map(|cap| Ok((capture_group_to_i64(&cap, "start")?, capture_group_to_i64(&cap, "end")?)))
+        .collect::<Result<Vec<(i64, i64)>, String>>()?;
+    match ranges.iter().find(|(start, end)| start > end) {
+        Some((start, end)) => Err(format!("inverted range {start}-{end}")),
+        None => Ok(ranges),
+    }
+}
+
+/*
+ * Parses all non-empty lines. Invalid lines are errors unless lenient, in which case they are
+ * skipped with a warning. Errors are reported with their line numbers.
+ */
+fn parse_input(contents: &str, lenient: bool) -> Result<Vec<Vec<(i64, i64)>>, Vec<String>> {
+    let mut groups = vec![];
+    let mut errors = vec![];
+    for (line_number, line) in contents.split('\n').enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_line(line) {
+            Ok(ranges) => groups.push(ranges),
+            Err(message) if lenient => println!("Warning: skipping line {}: {message}", line_number + 1),
+            Err(message) => errors.push(format!("line {}: {message}", line_number + 1)),
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(groups),
+        false => Err(errors),
+    }
+}
+
+pub fn day04() {
+    println!("starting day 04");
+
+    let contents = fs::read_to_string("data/2022/04_1_overlapping_assignments.txt")
+        .expect("Could not read file");
+
+    let groups = match parse_input(&contents, cli::has_flag("--lenient")) {
+        Ok(groups) => groups,
+        Err(errors) => {
+            errors.iter().for_each(|error| println!("Error: {error}"));
+            println!("{} invalid lines, fix the input or run with --lenient to skip them", errors.len());
+            return;
+        }
+    };
+
+    let mut fully_contained_ranges = 0;
+    let mut partial_overlapping_ranges = 0;
+    let mut covered_by_anyone = IntervalSet::new();
+    // +1 where an assignment starts, -1 right after it ends
+    let mut coverage_changes: BTreeMap<i64, i64> = BTreeMap::new();
+    for ranges in groups {
+        let group = ranges.iter()
+            .map(|&(start, end)| IntervalSet::from_range(start, end))
+            .collect::<Vec<IntervalSet>>();
+
+        if has_contained_assignment(&group) {
+            fully_contained_ranges += 1;
+        }
+        if has_overlapping_assignments(&group) {
+            partial_overlapping_ranges += 1;
+        }
+        let covered_by_group = group.iter().fold(IntervalSet::new(), |accu, it| accu.union(it));
+        covered_by_anyone.merge(&covered_by_group);
+        for (start, end) in ranges {
+            *coverage_changes.entry(start).or_insert(0) += 1;
+            *coverage_changes.entry(end + 1).or_insert(0) -= 1;
+        }
+    }
+    println!("Pairs of elves with fully contained sections {fully_contained_ranges}, partially overlapping sections {partial_overlapping_ranges}");
+    // both parts come from the same pass, so memory is only reported for the whole day
+    answers::check(1, fully_contained_ranges);
+    answers::check(2, partial_overlapping_ranges);
+
+    let gaps = covered_by_anyone.gaps();
+    match gaps.is_empty() {
+        true => println!("All sections {covered_by_anyone} are assigned to at least one elf"),
+        false => println!("Sections assigned {covered_by_anyone}, not assigned to any elf: {gaps} ({} sections)", gaps.len()),
+    }
+
+    if cli::has_flag("--sweep") {
+        print_sweep(&coverage_changes);
+    }
+}
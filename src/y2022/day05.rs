@@ -0,0 +1,711 @@
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead};
+use std::rc::Rc;
+use std::str::{FromStr, Split};
+use strum_macros::EnumString;
+use crate::answers;
+use crate::cli;
+use crate::mem;
+
+fn capture_group_to_usize(cap: &Captures, name: &str) -> Result<usize, String> {
+    let value = cap.name(name).unwrap().as_str();
+    usize::from_str(value).map_err(|_| format!("{value} is too large"))
+}
+lazy_static! {
+    static ref RE: Regex =
+        Regex::new(r"move (?P<count>\d+) from (?P<from>\d+) to (?P<to>\d+)").unwrap();
+}
+
+// use vec as stacks: "front" is "bottom", "end" is "top"
+type Stacks = HashMap<usize, Vec<char>>;
+
+// stack indices are 0-based, the input counts from 1
+#[derive(Debug, Clone, Copy)]
+struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+    line_number: usize,
+}
+
+// in the input format
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from + 1, self.to + 1)
+    }
+}
+
+/*
+ * A crane model decides how the crates of one move travel from the source to the target stack.
+ * All models move exactly `count` crates, only the resulting order differs.
+ */
+trait Crane {
+    fn name(&self) -> String;
+    fn apply(&self, stacks: &mut Stacks, mv: &Move);
+}
+
+// picks up `count` crates from the top of a stack in one go, keeping their order
+fn lift(stacks: &mut Stacks, from: usize, to: usize, count: usize) {
+    let source_stack = stacks
+        .get_mut(&from)
+        .unwrap_or_else(|| panic!("No stack with from index {from}"));
+    let mut moved_crates = source_stack.split_off(source_stack.len() - count);
+
+    stacks.get_mut(&to)
+        .unwrap_or_else(|| panic!("Could not access stack {to} to push to"))
+        .append(&mut moved_crates);
+}
+
+// moves one crate at a time
+struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        String::from("CrateMover 9000")
+    }
+
+    fn apply(&self, stacks: &mut Stacks, mv: &Move) {
+        for _ in 0..mv.count {
+            lift(stacks, mv.from, mv.to, 1);
+        }
+    }
+}
+
+// moves all crates at once
+struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        String::from("CrateMover 9001")
+    }
+
+    fn apply(&self, stacks: &mut Stacks, mv: &Move) {
+        lift(stacks, mv.from, mv.to, mv.count);
+    }
+}
+
+// moves at most `capacity` crates per lift
+struct CapacityLimited {
+    capacity: usize,
+}
+
+impl Crane for CapacityLimited {
+    fn name(&self) -> String {
+        format!("capacity limited crane ({} crates per lift)", self.capacity)
+    }
+
+    fn apply(&self, stacks: &mut Stacks, mv: &Move) {
+        let mut remaining = mv.count;
+        while remaining > 0 {
+            let lifted = remaining.min(self.capacity);
+            lift(stacks, mv.from, mv.to, lifted);
+            remaining -= lifted;
+        }
+    }
+}
+
+// moves full batches of `batch_size` crates at once, the remaining crates one at a time
+struct Batched {
+    batch_size: usize,
+}
+
+impl Crane for Batched {
+    fn name(&self) -> String {
+        format!("batch crane ({} crates per batch)", self.batch_size)
+    }
+
+    fn apply(&self, stacks: &mut Stacks, mv: &Move) {
+        for _ in 0..mv.count / self.batch_size {
+            lift(stacks, mv.from, mv.to, self.batch_size);
+        }
+        for _ in 0..mv.count % self.batch_size {
+            lift(stacks, mv.from, mv.to, 1);
+        }
+    }
+}
+
+// `9000`, `9001`, `limited:K` or `batch:B`
+fn crane_from_str(spec: &str) -> Box<dyn Crane> {
+    let size = |value: &str| match usize::from_str(value) {
+        Ok(size) if size > 0 => size,
+        _ => panic!("Invalid crane size {value} in {spec}"),
+    };
+    match spec.split_once(':') {
+        None if spec == "9000" => Box::new(CrateMover9000),
+        None if spec == "9001" => Box::new(CrateMover9001),
+        Some(("limited", capacity)) => Box::new(CapacityLimited { capacity: size(capacity) }),
+        Some(("batch", batch_size)) => Box::new(Batched { batch_size: size(batch_size) }),
+        _ => panic!("Unknown crane {spec}, expected 9000, 9001, limited:K or batch:B"),
+    }
+}
+
+pub fn day05() {
+    println!("starting day 05");
+
+    // a different starting diagram (with or without moves), e.g. one written by --dump
+    let start = cli::option_value("--start");
+    let path = start.as_deref().unwrap_or("data/2022/05_1_stack_rearrangement.txt");
+    let contents = fs::read_to_string(path).expect("Could not read file");
+
+    let stacks: Stacks = parse_input(&mut contents.split('\n'));
+    let moves = parse_moves(&contents);
+
+    let on_invalid = cli::parsed_option::<OnInvalid>("--on-invalid").unwrap_or(OnInvalid::Abort);
+    let moves = match validate(&moves, &stacks) {
+        Ok(moves) => moves,
+        Err(invalid) if on_invalid == OnInvalid::Abort => {
+            println!("Error: {invalid}");
+            println!("Fix the moves or run with --on-invalid clamp|skip");
+            return;
+        }
+        Err(_) => make_valid(&moves, &stacks, on_invalid),
+    };
+
+    let mut stack_after_part1 = run(&CrateMover9000, &moves, stacks.clone());
+    print_top_crates(1, &mut stack_after_part1, start.is_none());
+    mem::report("part 1");
+    let mut stack_after_part2 = run(&CrateMover9001, &moves, stacks.clone());
+    print_top_crates(2, &mut stack_after_part2, start.is_none());
+
+    if let Some(spec) = cli::option_value("--crane") {
+        let crane = crane_from_str(&spec);
+        println!("Using {}", crane.name());
+        let stacks_after = run(crane.as_ref(), &moves, stacks.clone());
+        println!("Top crates: {}", top_crates(&stacks_after));
+    }
+
+    // the replay and the dump use the crane from --crane as well
+    let spec = cli::option_value("--crane").unwrap_or(String::from("9000"));
+    if let Some(path) = cli::option_value("--dump") {
+        let stacks_after = run(crane_from_str(&spec).as_ref(), &moves, stacks.clone());
+        // without moves, but ready to have some appended
+        fs::write(&path, serialize_stacks(&stacks_after) + "\n\n").expect("Could not write diagram");
+        println!("Wrote final stacks to {path}");
+    }
+    if let Some(path) = cli::option_value("--plan") {
+        let target_contents = fs::read_to_string(&path).expect("Could not read target diagram");
+        let target = parse_input(&mut target_contents.split('\n'));
+        let max_states = cli::parsed_option::<usize>("--max-states").unwrap_or(1_000_000);
+        let crane = crane_from_str(&spec);
+        match plan(crane.as_ref(), &stacks, &target, max_states) {
+            Ok(planned_moves) if run(crane.as_ref(), &planned_moves, stacks.clone()) != target => {
+                println!("Error: the planned moves do not reach the target");
+            }
+            Ok(planned_moves) => {
+                println!("{} moves with {}:", planned_moves.len(), crane.name());
+                println!("{}\n", serialize_stacks(&stacks));
+                planned_moves.iter().for_each(|mv| println!("{mv}"));
+            }
+            Err(message) => println!("No plan: {message}"),
+        }
+    }
+    if cli::has_flag("--replay") {
+        replay(Replay::new(crane_from_str(&spec), &moves, stacks));
+    }
+}
+
+fn top_crates(stacks: &Stacks) -> String {
+    stacks
+        .iter()
+        .sorted_by_key(|x| x.0)
+        .map(|(_, v)| v.last().unwrap_or(&' '))
+        .collect::<String>()
+}
+
+// only the puzzle input has recorded answers
+fn print_top_crates(part: u8, stack_after_part1: &mut Stacks, check_answer: bool) {
+    let top_crates_part1 = top_crates(stack_after_part1);
+    println!("Top crates: {top_crates_part1}");
+    if check_answer {
+        answers::check(part, top_crates_part1);
+    }
+}
+
+fn parse_input(lines: &mut Split<char>) -> Stacks {
+    let mut stacks = HashMap::new();
+    // parse initial stack setup up to the empty line before the moves
+    // iterate over lines and take every 4th char
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+        for (i, c) in line.chars().enumerate().skip(1).step_by(4) {
+            let stack_index = i / 4;
+            // set up initial stack container if not already present
+            if !stacks.contains_key(&stack_index) {
+                stacks.insert(stack_index, Vec::new());
+            }
+
+            if c.is_uppercase() {
+                stacks.get_mut(&stack_index).unwrap().push(c);
+            }
+        }
+    }
+    // reverse all stacks as they have been inserted in the wrong order
+    // (file goes "top-to-bottom" while we always append to the stack)
+    for (_, stack) in stacks.iter_mut() {
+        stack.reverse()
+    }
+    stacks
+}
+
+// a move line with numbers which do not fit or a stack 0
+#[derive(Debug)]
+struct UnparsableMove {
+    line: String,
+    line_number: usize,
+    reason: String,
+}
+
+fn parse_move(cap: &Captures, line_number: usize) -> Result<Move, String> {
+    let stack_index = |name| {
+        capture_group_to_usize(cap, name)?.checked_sub(1).ok_or_else(|| String::from("there is no stack 0"))
+    };
+    Ok(Move {
+        count: capture_group_to_usize(cap, "count")?,
+        from: stack_index("from")?,
+        to: stack_index("to")?,
+        line_number,
+    })
+}
+
+fn parse_moves(contents: &str) -> Vec<Result<Move, UnparsableMove>> {
+    contents
+        .split('\n')
+        .enumerate()
+        .filter_map(|(line_number, line)| RE.captures(line).map(|cap| (line_number + 1, line, cap)))
+        .map(|(line_number, line, cap)| {
+            parse_move(&cap, line_number).map_err(|reason| UnparsableMove { line: line.to_string(), line_number, reason })
+        })
+        .collect()
+}
+
+// what to do with moves referring to missing stacks or taking more crates than there are
+#[derive(EnumString, Clone, Copy, PartialEq, Debug)]
+#[strum(serialize_all = "snake_case")]
+enum OnInvalid {
+    Abort,
+    // take as many crates as there are
+    Clamp,
+    Skip,
+}
+
+#[derive(Debug)]
+struct InvalidMove {
+    // as in the input
+    line: String,
+    line_number: usize,
+    reason: String,
+    // stack heights right before the move
+    heights: Vec<usize>,
+}
+
+impl fmt::Display for InvalidMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let heights = self.heights.iter().enumerate().map(|(i, height)| format!("{}: {height}", i + 1)).join(", ");
+        write!(f, "line {}: {}: {}, stack heights {heights}", self.line_number, self.line, self.reason)
+    }
+}
+
+fn heights(stacks: &Stacks) -> Vec<usize> {
+    let stack_count = stacks.keys().max().map_or(0, |max| max + 1);
+    (0..stack_count).map(|index| stacks.get(&index).map_or(0, |stack| stack.len())).collect()
+}
+
+fn check_move(mv: &Move, heights: &[usize]) -> Result<(), String> {
+    for index in [mv.from, mv.to] {
+        if index >= heights.len() {
+            return Err(format!("there is no stack {}", index + 1));
+        }
+    }
+    match mv.count > heights[mv.from] {
+        true => Err(format!("stack {} only holds {} crates", mv.from + 1, heights[mv.from])),
+        false => Ok(()),
+    }
+}
+
+// every crane moves the same number of crates, so following the stack heights is enough
+fn validate(moves: &[Result<Move, UnparsableMove>], stacks: &Stacks) -> Result<Vec<Move>, InvalidMove> {
+    let mut heights = heights(stacks);
+    let mut valid_moves = vec![];
+    for mv in moves {
+        let mv = match mv {
+            Ok(mv) => *mv,
+            Err(UnparsableMove { line, line_number, reason }) => {
+                return Err(InvalidMove { line: line.clone(), line_number: *line_number, reason: reason.clone(), heights });
+            }
+        };
+        if let Err(reason) = check_move(&mv, &heights) {
+            return Err(InvalidMove { line: mv.to_string(), line_number: mv.line_number, reason, heights });
+        }
+        heights[mv.from] -= mv.count;
+        heights[mv.to] += mv.count;
+        valid_moves.push(mv);
+    }
+    Ok(valid_moves)
+}
+
+// clamps or skips all invalid moves with a warning for each, unparsable moves are always skipped
+fn make_valid(moves: &[Result<Move, UnparsableMove>], stacks: &Stacks, on_invalid: OnInvalid) -> Vec<Move> {
+    let mut heights = heights(stacks);
+    let mut valid_moves = vec![];
+    for mv in moves {
+        let mut mv = match mv {
+            Ok(mv) => *mv,
+            Err(UnparsableMove { line, line_number, reason }) => {
+                println!("Warning: line {line_number}: skipping {line}: {reason}");
+                continue;
+            }
+        };
+        if let Err(reason) = check_move(&mv, &heights) {
+            let missing_stack = mv.from >= heights.len() || mv.to >= heights.len();
+            if on_invalid == OnInvalid::Skip || missing_stack {
+                println!("Warning: line {}: skipping {mv}: {reason}", mv.line_number);
+                continue;
+            }
+            mv.count = heights[mv.from];
+            println!("Warning: line {}: moving only {} crates: {reason}", mv.line_number, mv.count);
+        }
+        heights[mv.from] -= mv.count;
+        heights[mv.to] += mv.count;
+        valid_moves.push(mv);
+    }
+    valid_moves
+}
+
+fn run(crane: &dyn Crane, moves: &[Move], mut stacks: Stacks) -> Stacks {
+    for mv in moves {
+        crane.apply(&mut stacks, mv);
+    }
+    stacks
+}
+
+/*
+ * Steps forward and backward through the moves. Every applied move remembers the crates it took
+ * off the source stack (in their original order), which is all an undo needs: the same number of
+ * crates comes off the target stack and the remembered ones go back onto the source stack.
+ */
+struct Replay<'a> {
+    crane: Box<dyn Crane>,
+    moves: &'a [Move],
+    stacks: Stacks,
+    history: Vec<Vec<char>>,
+}
+
+impl<'a> Replay<'a> {
+    fn new(crane: Box<dyn Crane>, moves: &'a [Move], stacks: Stacks) -> Replay<'a> {
+        Replay { crane, moves, stacks, history: vec![] }
+    }
+
+    // number of moves applied so far
+    fn position(&self) -> usize {
+        self.history.len()
+    }
+
+    fn step_forward(&mut self) -> bool {
+        let mv = match self.moves.get(self.position()) {
+            Some(mv) => mv,
+            None => return false,
+        };
+        let source_stack = &self.stacks[&mv.from];
+        self.history.push(source_stack[source_stack.len() - mv.count..].to_vec());
+        self.crane.apply(&mut self.stacks, mv);
+        true
+    }
+
+    fn step_back(&mut self) -> bool {
+        let mut moved_crates = match self.history.pop() {
+            Some(moved_crates) => moved_crates,
+            None => return false,
+        };
+        let mv = &self.moves[self.position()];
+        let target_stack = self.stacks.get_mut(&mv.to).unwrap();
+        target_stack.truncate(target_stack.len() - mv.count);
+        self.stacks.get_mut(&mv.from).unwrap().append(&mut moved_crates);
+        true
+    }
+}
+
+/*
+ * The stacks in the exact format of the puzzle drawing: bracketed crates above the numbered
+ * columns, every line padded to the full width. Parsing the result gives the same stacks again.
+ */
+fn serialize_stacks(stacks: &Stacks) -> String {
+    let stack_count = heights(stacks).len();
+    let height = stacks.values().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut lines = vec![];
+    for level in (0..height).rev() {
+        let line = (0..stack_count)
+            .map(|index| match stacks.get(&index).and_then(|stack| stack.get(level)) {
+                Some(c) => format!("[{c}]"),
+                None => String::from("   "),
+            })
+            .join(" ");
+        lines.push(line);
+    }
+    lines.push((0..stack_count).map(|index| format!(" {} ", index + 1)).join(" "));
+    lines.join("\n")
+}
+
+// interactive: `n` (or enter) applies the next move, `b` undoes the last one, `q` quits
+fn replay(mut replay: Replay) {
+    println!("Replaying {} moves with {}, commands: n(ext), b(ack), q(uit)", replay.moves.len(), replay.crane.name());
+    println!("{}", serialize_stacks(&replay.stacks));
+    for command in io::stdin().lock().lines() {
+        let command = command.expect("Could not read command");
+        let position = replay.position();
+        match command.trim() {
+            "" | "n" => match replay.step_forward() {
+                true => println!("step {}/{}: {}", position + 1, replay.moves.len(), replay.moves[position]),
+                false => println!("no moves left"),
+            },
+            "b" => match replay.step_back() {
+                true => println!("step {}/{}: undid {}", position - 1, replay.moves.len(), replay.moves[position - 1]),
+                false => println!("nothing to undo"),
+            },
+            "q" => break,
+            other => {
+                println!("unknown command {other:?}, expected n, b or q");
+                continue;
+            }
+        }
+        println!("{}", serialize_stacks(&replay.stacks));
+    }
+    println!("Top crates: {}", top_crates(&replay.stacks));
+}
+
+/*
+ * All stacks bottom to top in one slice, each stack followed by STACK_END. Crates are stored as
+ * their index in the sorted list of all crate labels, starting at 1.
+ */
+type State = Rc<[u8]>;
+const STACK_END: u8 = 0;
+
+fn to_state(stacks: &Stacks, labels: &[char]) -> State {
+    let mut state = vec![];
+    for index in 0..heights(stacks).len() {
+        for label in stacks.get(&index).into_iter().flatten() {
+            state.push(labels.binary_search(label).unwrap() as u8 + 1);
+        }
+        state.push(STACK_END);
+    }
+    state.into()
+}
+
+fn state_stacks(state: &[u8]) -> Vec<&[u8]> {
+    let mut stacks = state.split(|&c| c == STACK_END).collect::<Vec<&[u8]>>();
+    // nothing follows the last STACK_END
+    stacks.pop();
+    stacks
+}
+
+// where a crane puts down `count` lifted crates: position k of the target stack (bottom to top)
+// receives the crate from position order[k] of the lifted crates
+fn lift_order(crane: &dyn Crane, count: usize) -> Vec<usize> {
+    let labels = (0..count).map(|i| char::from_u32(0x100 + i as u32).unwrap()).collect::<Vec<char>>();
+    let mut stacks = Stacks::from([(0, labels), (1, vec![])]);
+    crane.apply(&mut stacks, &Move { count, from: 0, to: 1, line_number: 0 });
+    stacks[&1].iter().map(|&c| c as usize - 0x100).collect()
+}
+
+fn apply_to_state(stacks: &[&[u8]], from: usize, to: usize, order: &[usize]) -> Vec<u8> {
+    let source = stacks[from];
+    let lifted = &source[source.len() - order.len()..];
+    let mut next = Vec::with_capacity(stacks.iter().map(|stack| stack.len() + 1).sum());
+    for (index, stack) in stacks.iter().enumerate() {
+        match index == from {
+            true => next.extend_from_slice(&stack[..stack.len() - order.len()]),
+            false => next.extend_from_slice(stack),
+        }
+        if index == to {
+            next.extend(order.iter().map(|&i| lifted[i]));
+        }
+        next.push(STACK_END);
+    }
+    next
+}
+
+/*
+ * Lower bound for the number of moves left: a stack with crates above its correctly stacked
+ * bottom part needs a move away from it, a stack lacking crates of its target needs a move onto
+ * it, and every move is one of each. A move changes the bound by at most one.
+ */
+fn moves_needed(stacks: &[&[u8]], target: &[&[u8]]) -> usize {
+    let (mut away, mut onto) = (0, 0);
+    for (stack, goal) in stacks.iter().zip(target) {
+        let correct = stack.iter().zip(goal.iter()).take_while(|(a, b)| a == b).count();
+        if stack.len() > correct {
+            away += 1;
+        }
+        if goal.len() > correct {
+            onto += 1;
+        }
+    }
+    away.max(onto)
+}
+
+/*
+ * Fewest moves after which the crane has rearranged the stacks into the target, found by an A*
+ * search guided by moves_needed. Gives up after max_states states.
+ */
+fn plan(crane: &dyn Crane, start: &Stacks, target: &Stacks, max_states: usize) -> Result<Vec<Move>, String> {
+    let stack_count = (heights(start).len(), heights(target).len());
+    if stack_count.0 != stack_count.1 {
+        return Err(format!("{} stacks at the start, but {} in the target", stack_count.0, stack_count.1));
+    }
+    let sorted_crates = |stacks: &Stacks| stacks.values().flatten().copied().sorted().collect::<Vec<char>>();
+    if sorted_crates(start) != sorted_crates(target) {
+        return Err(String::from("the target does not consist of the same crates"));
+    }
+    let labels = sorted_crates(start).into_iter().dedup().collect::<Vec<char>>();
+    if labels.len() >= u8::MAX as usize {
+        return Err(format!("{} different crate labels, at most {} are supported", labels.len(), u8::MAX - 1));
+    }
+    let (start, target) = (to_state(start, &labels), to_state(target, &labels));
+    let target_stacks = state_stacks(&target);
+    let lift_orders = (0..=start.len()).map(|count| lift_order(crane, count)).collect::<Vec<Vec<usize>>>();
+
+    // every state found so far with the fewest moves known to reach it and the last of these moves
+    let mut states: Vec<State> = vec![Rc::clone(&start)];
+    let mut moves_to: Vec<usize> = vec![0];
+    let mut reached_by: Vec<Option<(usize, Move)>> = vec![None];
+    let mut index_of: HashMap<State, usize> = HashMap::from([(Rc::clone(&start), 0)]);
+    // smallest estimated total first, the most advanced state among equal estimates
+    let mut open = BinaryHeap::from([Reverse((moves_needed(&state_stacks(&start), &target_stacks), Reverse(0), 0))]);
+
+    while let Some(Reverse((_, Reverse(moves_so_far), index))) = open.pop() {
+        if moves_so_far > moves_to[index] {
+            continue;
+        }
+        if states[index] == target {
+            let mut moves = vec![];
+            let mut current = index;
+            while let Some((previous, mv)) = reached_by[current] {
+                moves.push(mv);
+                current = previous;
+            }
+            moves.reverse();
+            return Ok(moves);
+        }
+        let state = Rc::clone(&states[index]);
+        let stacks = state_stacks(&state);
+        for from in 0..stacks.len() {
+            for to in (0..stacks.len()).filter(|&to| to != from) {
+                for (count, order) in lift_orders.iter().enumerate().take(stacks[from].len() + 1).skip(1) {
+                    let next = apply_to_state(&stacks, from, to, order);
+                    // not from an input file
+                    let mv = Move { count, from, to, line_number: 0 };
+                    let next_index = match index_of.get(next.as_slice()) {
+                        Some(&known) if moves_to[known] <= moves_so_far + 1 => continue,
+                        Some(&known) => known,
+                        None if states.len() >= max_states => {
+                            return Err(format!("gave up after {max_states} states, raise --max-states"));
+                        }
+                        None => {
+                            let next = State::from(next);
+                            index_of.insert(Rc::clone(&next), states.len());
+                            states.push(next);
+                            moves_to.push(usize::MAX);
+                            reached_by.push(None);
+                            states.len() - 1
+                        }
+                    };
+                    moves_to[next_index] = moves_so_far + 1;
+                    reached_by[next_index] = Some((index, mv));
+                    let estimate = moves_so_far + 1 + moves_needed(&state_stacks(&states[next_index]), &target_stacks);
+                    open.push(Reverse((estimate, Reverse(moves_so_far + 1), next_index)));
+                }
+            }
+        }
+    }
+    Err(format!("the target cannot be reached with {}", crane.name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{make_valid, parse_input, parse_moves, plan, run, serialize_stacks, validate, CrateMover9000, CrateMover9001, OnInvalid, Stacks};
+    use std::fs;
+
+    fn parse(diagram: &str) -> Stacks {
+        parse_input(&mut diagram.split('\n'))
+    }
+
+    fn round_trip(stacks: &Stacks) {
+        let diagram = serialize_stacks(stacks);
+        assert_eq!(&parse(&diagram), stacks, "Serialized diagram does not parse back:\n{diagram}");
+    }
+
+    #[test]
+    fn real_diagram_is_reproduced_exactly() {
+        let contents = fs::read_to_string("data/2022/05_1_stack_rearrangement.txt").expect("Could not read file");
+        let diagram = contents.split("\n\n").next().unwrap();
+        assert_eq!(serialize_stacks(&parse(&contents)), diagram);
+    }
+
+    #[test]
+    fn demo_diagram_round_trip() {
+        // the demo drawing lacks trailing spaces, so only the stacks have to match
+        let contents = fs::read_to_string("data/2022/05_1_demo.txt").expect("Could not read file");
+        let stacks = parse(&contents);
+        assert_eq!(stacks.len(), 3);
+        round_trip(&stacks);
+    }
+
+    #[test]
+    fn empty_stacks_round_trip() {
+        round_trip(&Stacks::from([(0, vec!['A']), (1, vec![]), (2, vec!['B', 'C'])]));
+        round_trip(&Stacks::from([(0, vec!['A', 'B']), (1, vec![])]));
+        round_trip(&Stacks::from([(0, vec![]), (1, vec![])]));
+    }
+
+    #[test]
+    fn plan_reaches_the_target_in_fewest_moves() {
+        let contents = fs::read_to_string("data/2022/05_1_demo.txt").expect("Could not read file");
+        let start = parse(&contents);
+        let moves = validate(&parse_moves(&contents), &start).unwrap();
+        for crane in [&CrateMover9000 as &dyn super::Crane, &CrateMover9001] {
+            let target = run(crane, &moves, start.clone());
+            let planned = plan(crane, &start, &target, 100_000).unwrap();
+            assert!(planned.len() <= moves.len());
+            assert_eq!(run(crane, &planned, start.clone()), target);
+        }
+        // a single move cannot be done in fewer moves
+        let target = run(&CrateMover9000, &moves[..1], start.clone());
+        assert_eq!(plan(&CrateMover9000, &start, &target, 100_000).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn plan_rejects_other_crates() {
+        let start = Stacks::from([(0, vec!['A']), (1, vec!['B'])]);
+        let target = Stacks::from([(0, vec!['A', 'C']), (1, vec![])]);
+        assert!(plan(&CrateMover9000, &start, &target, 100).is_err());
+    }
+
+    #[test]
+    fn invalid_moves_are_reported_with_their_line() {
+        let stacks = Stacks::from([(0, vec!['A', 'B']), (1, vec![])]);
+        let reason = |moves: &str| validate(&parse_moves(moves), &stacks).unwrap_err().to_string();
+        assert_eq!(reason("move 1 from 0 to 2"), "line 1: move 1 from 0 to 2: there is no stack 0, stack heights 1: 2, 2: 0");
+        assert_eq!(
+            reason("move 1 from 1 to 2\nmove 99999999999999999999 from 1 to 2"),
+            "line 2: move 99999999999999999999 from 1 to 2: 99999999999999999999 is too large, stack heights 1: 1, 2: 1"
+        );
+        assert_eq!(reason("move 1 from 1 to 3"), "line 1: move 1 from 1 to 3: there is no stack 3, stack heights 1: 2, 2: 0");
+        assert_eq!(reason("move 3 from 1 to 2"), "line 1: move 3 from 1 to 2: stack 1 only holds 2 crates, stack heights 1: 2, 2: 0");
+    }
+
+    #[test]
+    fn unparsable_moves_are_skipped() {
+        let stacks = Stacks::from([(0, vec!['A', 'B']), (1, vec![])]);
+        let moves = parse_moves("move 1 from 0 to 2\nmove 3 from 1 to 2");
+        let clamped = make_valid(&moves, &stacks, OnInvalid::Clamp);
+        assert_eq!(clamped.len(), 1);
+        assert_eq!((clamped[0].count, clamped[0].line_number), (2, 2));
+    }
+}
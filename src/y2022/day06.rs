@@ -0,0 +1,292 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use itertools::Itertools;
+use crate::answers;
+use crate::cli;
+use crate::input;
+
+
+pub fn day06() {
+    println!("starting day 06");
+
+    if cli::has_flag("--stdin") {
+        decode(io::stdin().lock());
+        return;
+    }
+
+    let path = "data/2022/06_1_signal_input.txt";
+
+    // part 1 and 2 and any further window size from --window in a single pass, so memory is only
+    // reported for the whole day
+    let mut sizes = vec![4, 14];
+    sizes.extend(cli::parsed_option::<usize>("--window"));
+    if sizes.contains(&0) {
+        println!("--window must be at least 1");
+        return;
+    }
+    let first = first_markers(input::bytes(path), &sizes);
+
+    println!("Part 1: Signal start marker: {}", display_marker(first[0]));
+    answers::check(1, display_marker(first[0]));
+    println!("Part 2: Message start marker: {}", display_marker(first[1]));
+    answers::check(2, display_marker(first[1]));
+    if let Some(position) = first.get(2) {
+        println!("First marker of {} distinct characters: {}", sizes[2], display_marker(*position));
+    }
+
+    if cli::has_flag("--all") {
+        for (size, positions) in sizes.iter().zip(all_markers(input::bytes(path), &sizes)) {
+            println!("{} markers of {size} distinct characters: {}", positions.len(), positions.iter().join(","));
+        }
+    }
+    if cli::has_flag("--events") {
+        // unbuffered, the decoder buffers any reader itself
+        decode(File::open(path).expect("Could not read file"));
+    }
+}
+
+fn display_marker(position: Option<usize>) -> String {
+    position.map_or(String::from("none"), |position| position.to_string())
+}
+
+// number of distinct bytes among the last `size` bytes, updated in constant time per byte
+struct Window {
+    size: usize,
+    counts: [usize; 256],
+    distinct: usize,
+}
+
+impl Window {
+    fn new(size: usize) -> Window {
+        Window { size, counts: [0; 256], distinct: 0 }
+    }
+
+    // outgoing is the byte which just dropped out of the window, if any
+    fn push(&mut self, incoming: u8, outgoing: Option<u8>) -> bool {
+        if let Some(outgoing) = outgoing {
+            self.counts[outgoing as usize] -= 1;
+            if self.counts[outgoing as usize] == 0 {
+                self.distinct -= 1;
+            }
+        }
+        self.counts[incoming as usize] += 1;
+        if self.counts[incoming as usize] == 1 {
+            self.distinct += 1;
+        }
+        self.distinct == self.size
+    }
+}
+
+/*
+ * Looks for markers of several sizes at once. Only the last bytes up to the largest window size
+ * are kept, every window finds the byte leaving it at its own distance from the end.
+ */
+struct MarkerScanner {
+    windows: Vec<Window>,
+    recent: VecDeque<u8>,
+    // bytes kept: the largest window and the byte which just left it
+    kept: usize,
+    // for every window size whether a marker ends with the last byte
+    is_marker: Vec<bool>,
+}
+
+impl MarkerScanner {
+    fn new(sizes: &[usize]) -> MarkerScanner {
+        let kept = sizes.iter().copied().max().unwrap_or(0) + 1;
+        MarkerScanner {
+            windows: sizes.iter().map(|&size| Window::new(size)).collect(),
+            recent: VecDeque::with_capacity(kept),
+            kept,
+            is_marker: vec![false; sizes.len()],
+        }
+    }
+
+    // for every window size whether a marker ends with this byte
+    fn push(&mut self, byte: u8) -> &[bool] {
+        self.recent.push_back(byte);
+        if self.recent.len() > self.kept {
+            self.recent.pop_front();
+        }
+        for (window, is_marker) in self.windows.iter_mut().zip(self.is_marker.iter_mut()) {
+            let outgoing = self.recent.len().checked_sub(window.size + 1).map(|index| self.recent[index]);
+            *is_marker = window.push(byte, outgoing);
+        }
+        &self.is_marker
+    }
+}
+
+// the signal ends at the first line break
+fn signal(input: impl Iterator<Item = u8>) -> impl Iterator<Item = u8> {
+    input.take_while(|&byte| byte != b'\n')
+}
+
+// position after the first marker for every window size, stops as soon as all are found
+fn first_markers(input: impl Iterator<Item = u8>, sizes: &[usize]) -> Vec<Option<usize>> {
+    let mut scanner = MarkerScanner::new(sizes);
+    let mut first = vec![None; sizes.len()];
+    for (i, byte) in signal(input).enumerate() {
+        for (found, &is_marker) in first.iter_mut().zip(scanner.push(byte)) {
+            if is_marker && found.is_none() {
+                *found = Some(i + 1);
+            }
+        }
+        if first.iter().all(Option::is_some) {
+            break;
+        }
+    }
+    first
+}
+
+// positions after every marker for every window size, overlapping markers included
+fn all_markers(input: impl Iterator<Item = u8>, sizes: &[usize]) -> Vec<Vec<usize>> {
+    let mut scanner = MarkerScanner::new(sizes);
+    let mut positions = vec![vec![]; sizes.len()];
+    for (i, byte) in signal(input).enumerate() {
+        for (found, &is_marker) in positions.iter_mut().zip(scanner.push(byte)) {
+            if is_marker {
+                found.push(i + 1);
+            }
+        }
+    }
+    positions
+}
+
+const PACKET_MARKER_SIZE: usize = 4;
+const MESSAGE_MARKER_SIZE: usize = 14;
+
+// offsets count the bytes of the signal up to and including the marker
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Event {
+    StartOfPacket(u64),
+    StartOfMessage(u64),
+    // the bytes after a start of message marker up to the packet marker heading the next message
+    Message { start: u64, end: u64 },
+}
+
+/*
+ * Decodes a signal of any length read byte by byte into packets, each a packet marker followed by a
+ * message marker and the message. Packet markers of four distinct bytes turn up every few bytes, so
+ * messages are delimited by message markers: the packet of a message marker is headed by the last
+ * packet marker which ends before the message marker begins, and the previous message ends where
+ * that packet marker begins. A message marker without such a packet marker since the previous one
+ * is part of the message, and the last message runs to the end of the signal. Like the puzzle
+ * input, the signal ends at the first line break or at the end of the stream.
+ */
+struct Decoder<R: Read> {
+    bytes: io::Bytes<BufReader<R>>,
+    scanner: MarkerScanner,
+    offset: u64,
+    // ends of packet markers which overlap the last MESSAGE_MARKER_SIZE bytes
+    recent_packets: VecDeque<u64>,
+    // end of the last packet marker before those bytes
+    last_packet: Option<u64>,
+    message_start: Option<u64>,
+    pending: VecDeque<Event>,
+    finished: bool,
+}
+
+impl<R: Read> Decoder<R> {
+    fn new(reader: R) -> Decoder<R> {
+        Decoder {
+            bytes: BufReader::new(reader).bytes(),
+            scanner: MarkerScanner::new(&[PACKET_MARKER_SIZE, MESSAGE_MARKER_SIZE]),
+            offset: 0,
+            recent_packets: VecDeque::new(),
+            last_packet: None,
+            message_start: None,
+            pending: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    fn end_message(&mut self, end: u64) {
+        if let Some(start) = self.message_start.take() {
+            self.pending.push_back(Event::Message { start, end });
+        }
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.offset += 1;
+        let (packet_marker, message_marker) = match self.scanner.push(byte) {
+            &[packet_marker, message_marker] => (packet_marker, message_marker),
+            _ => unreachable!(),
+        };
+        if packet_marker {
+            self.recent_packets.push_back(self.offset);
+        }
+        let message_marker_begins = self.offset.saturating_sub(MESSAGE_MARKER_SIZE as u64);
+        while self.recent_packets.front().is_some_and(|&end| end <= message_marker_begins) {
+            self.last_packet = self.recent_packets.pop_front();
+        }
+        if let (true, Some(packet)) = (message_marker, self.last_packet) {
+            self.end_message(packet - PACKET_MARKER_SIZE as u64);
+            self.pending.push_back(Event::StartOfPacket(packet));
+            self.pending.push_back(Event::StartOfMessage(self.offset));
+            self.message_start = Some(self.offset);
+            // markers of the next packet are searched in fresh bytes
+            self.scanner = MarkerScanner::new(&[PACKET_MARKER_SIZE, MESSAGE_MARKER_SIZE]);
+            self.recent_packets.clear();
+            self.last_packet = None;
+        }
+    }
+}
+
+impl<R: Read> Iterator for Decoder<R> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        while self.pending.is_empty() && !self.finished {
+            match self.bytes.next() {
+                Some(Ok(b'\n')) | None => {
+                    self.finished = true;
+                    self.end_message(self.offset);
+                }
+                Some(Ok(byte)) => self.push(byte),
+                Some(Err(err)) => panic!("Could not read signal: {err}"),
+            }
+        }
+        self.pending.pop_front()
+    }
+}
+
+// prints every event followed by a summary
+fn decode(reader: impl Read) {
+    let (mut packets, mut messages, mut message_bytes) = (0u64, 0u64, 0u64);
+    for event in Decoder::new(reader) {
+        match event {
+            Event::StartOfPacket(offset) => {
+                packets += 1;
+                println!("start of packet at {offset}");
+            }
+            Event::StartOfMessage(offset) => println!("start of message at {offset}"),
+            Event::Message { start, end } => {
+                messages += 1;
+                message_bytes += end - start;
+                println!("message of {} bytes from {start} to {end}", end - start);
+            }
+        }
+    }
+    println!("{packets} packets, {messages} messages with {message_bytes} bytes");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decoder, Event};
+
+    #[test]
+    fn messages_run_to_the_next_packet() {
+        // filler of two distinct bytes contains no markers, each message marker begins with the
+        // last byte of its packet marker so that the marker cannot start a byte earlier
+        let signal = b"wxyzzABCDEFGHIJKLMababababpqrssOPQRSTUVWXYZ1ababab\nwxyz";
+        let events = Decoder::new(&signal[..]).collect::<Vec<Event>>();
+        assert_eq!(events, vec![
+            Event::StartOfPacket(4),
+            Event::StartOfMessage(18),
+            Event::Message { start: 18, end: 26 },
+            Event::StartOfPacket(30),
+            Event::StartOfMessage(44),
+            Event::Message { start: 44, end: 50 },
+        ]);
+    }
+}
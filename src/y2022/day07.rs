@@ -0,0 +1,493 @@
+use std::fs;
+use std::str::FromStr;
+use strum_macros::EnumString;
+use crate::answers;
+use crate::cli;
+use crate::mem;
+
+// index of a node in the file system arena
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    is_dir: bool,
+    // size of a file, total size of everything below a directory
+    size: usize,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+/*
+ * Directory tree with all nodes in one vector, nodes refer to each other by index. Nodes are
+ * never removed, so ids stay valid. Directory sizes are kept up to date while files are added.
+ */
+#[derive(Debug)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = NodeId(0);
+
+    pub fn new() -> FileSystem {
+        let root = Node { name: String::from("/"), is_dir: true, size: 0, parent: None, children: vec![] };
+        FileSystem { nodes: vec![root] }
+    }
+
+    fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    fn add(&mut self, parent: NodeId, name: &str, is_dir: bool, size: usize) -> NodeId {
+        assert!(self.node(parent).is_dir, "Cannot add {name} to file {}", self.path(parent));
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node { name: String::from(name), is_dir, size, parent: Some(parent), children: vec![] });
+        self.nodes[parent.0].children.push(id);
+        for ancestor in self.ancestors(id).collect::<Vec<NodeId>>() {
+            self.nodes[ancestor.0].size += size;
+        }
+        id
+    }
+
+    pub fn add_dir(&mut self, parent: NodeId, name: &str) -> NodeId {
+        self.add(parent, name, true, 0)
+    }
+
+    pub fn add_file(&mut self, parent: NodeId, name: &str, size: usize) -> NodeId {
+        self.add(parent, name, false, size)
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.node(id).name
+    }
+
+    pub fn size(&self, id: NodeId) -> usize {
+        self.node(id).size
+    }
+
+    pub fn is_dir(&self, id: NodeId) -> bool {
+        self.node(id).is_dir
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent
+    }
+
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.node(id).children.iter().copied()
+    }
+
+    pub fn child(&self, id: NodeId, name: &str) -> Option<NodeId> {
+        self.children(id).find(|&child| self.name(child) == name)
+    }
+
+    // parent, grandparent, ... up to the root
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        std::iter::successors(self.parent(id), |&ancestor| self.parent(ancestor))
+    }
+
+    // the node itself and everything below it, depth first with parents before their children
+    pub fn descendants(&self, id: NodeId) -> Vec<NodeId> {
+        let mut result = vec![];
+        let mut stack = vec![id];
+        while let Some(current) = stack.pop() {
+            result.push(current);
+            stack.extend(self.node(current).children.iter().rev());
+        }
+        result
+    }
+
+    // absolute path like /a/b.txt
+    pub fn path(&self, id: NodeId) -> String {
+        if id == FileSystem::ROOT {
+            return String::from("/");
+        }
+        let mut names = self.ancestors(id)
+            .filter(|&ancestor| ancestor != FileSystem::ROOT)
+            .map(|ancestor| self.name(ancestor))
+            .collect::<Vec<&str>>();
+        names.reverse();
+        names.push(self.name(id));
+        format!("/{}", names.join("/"))
+    }
+
+    // node at an absolute path
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(FileSystem::ROOT, |current, name| self.child(current, name))
+    }
+}
+
+// order of the entries of a directory in query results
+#[derive(EnumString, Clone, Copy, PartialEq, Debug)]
+#[strum(serialize_all = "snake_case")]
+pub enum Order {
+    // as in the transcript
+    Listed,
+    Name,
+    // largest first
+    Size,
+}
+
+#[derive(EnumString, Clone, Copy, PartialEq, Debug)]
+pub enum NodeType {
+    #[strum(serialize = "d")]
+    Dir,
+    #[strum(serialize = "f")]
+    File,
+}
+
+// like the -size argument of find: +N is more than N, -N less than N, N exactly N
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SizeFilter {
+    Above(usize),
+    Below(usize),
+    Exactly(usize),
+}
+
+impl FromStr for SizeFilter {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<SizeFilter, Self::Err> {
+        Ok(match s.as_bytes().first() {
+            Some(b'+') => SizeFilter::Above(usize::from_str(&s[1..])?),
+            Some(b'-') => SizeFilter::Below(usize::from_str(&s[1..])?),
+            _ => SizeFilter::Exactly(usize::from_str(s)?),
+        })
+    }
+}
+
+impl SizeFilter {
+    pub fn matches(self, size: usize) -> bool {
+        match self {
+            SizeFilter::Above(limit) => size > limit,
+            SizeFilter::Below(limit) => size < limit,
+            SizeFilter::Exactly(limit) => size == limit,
+        }
+    }
+}
+
+// queries modelled after the Unix commands of the same name
+impl FileSystem {
+    pub fn sorted_children(&self, id: NodeId, order: Order) -> Vec<NodeId> {
+        let mut children = self.children(id).collect::<Vec<NodeId>>();
+        match order {
+            Order::Listed => {}
+            Order::Name => children.sort_by(|&a, &b| self.name(a).cmp(self.name(b))),
+            Order::Size => children.sort_by_key(|&child| std::cmp::Reverse(self.size(child))),
+        }
+        children
+    }
+
+    // one line per node in the format of the puzzle description, with directory sizes
+    pub fn tree(&self, id: NodeId, order: Order) -> Vec<String> {
+        let mut lines = vec![];
+        let mut stack = vec![(id, 0)];
+        while let Some((current, depth)) = stack.pop() {
+            let kind = if self.is_dir(current) { "dir" } else { "file" };
+            lines.push(format!("{}- {} ({kind}, size={})", "  ".repeat(depth), self.name(current), self.size(current)));
+            stack.extend(self.sorted_children(current, order).into_iter().rev().map(|child| (child, depth + 1)));
+        }
+        lines
+    }
+
+    // directories at most max_depth levels below id with their total sizes, children before parents like du
+    pub fn du(&self, id: NodeId, max_depth: usize, order: Order) -> Vec<(NodeId, usize)> {
+        let mut result = vec![];
+        if max_depth > 0 {
+            for child in self.sorted_children(id, order).into_iter().filter(|&child| self.is_dir(child)) {
+                result.extend(self.du(child, max_depth - 1, order));
+            }
+        }
+        result.push((id, self.size(id)));
+        result
+    }
+
+    // all nodes below id (id included) with a matching size and type
+    pub fn find(&self, id: NodeId, size: Option<SizeFilter>, node_type: Option<NodeType>, order: Order) -> Vec<NodeId> {
+        let mut found = self.descendants(id)
+            .into_iter()
+            .filter(|&node| size.is_none_or(|size| size.matches(self.size(node))))
+            .filter(|&node| node_type.is_none_or(|node_type| (node_type == NodeType::Dir) == self.is_dir(node)))
+            .collect::<Vec<NodeId>>();
+        match order {
+            Order::Listed => {}
+            Order::Name => found.sort_by_key(|&node| self.path(node)),
+            Order::Size => found.sort_by_key(|&node| std::cmp::Reverse(self.size(node))),
+        }
+        found
+    }
+}
+
+// total size of every directory
+fn dir_sizes(fs: &FileSystem) -> Vec<usize> {
+    fs.descendants(FileSystem::ROOT)
+        .into_iter()
+        .filter(|&id| fs.is_dir(id))
+        .map(|id| fs.size(id))
+        .collect()
+}
+
+/*
+ * Rebuilds the file system from a transcript of `$ cd TARGET`, `$ ls` and the listed `dir NAME`
+ * and `SIZE NAME` entries. Names are anything without whitespace. Problems are collected as
+ * warnings with line numbers: unknown lines are skipped, directories entered with cd but never
+ * listed are created, entries listed again are only added once.
+ */
+pub fn parse_transcript(contents: &str) -> (FileSystem, Vec<String>) {
+    let mut fs = FileSystem::new();
+    let mut warnings = vec![];
+    let mut current_dir = FileSystem::ROOT;
+    for (line_number, line) in contents.split('\n').enumerate() {
+        let mut warn = |message: String| warnings.push(format!("line {}: {message}", line_number + 1));
+        match line.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            [] | ["$", "ls"] => {}
+            ["$", "cd", target] => current_dir = change_dir(&mut fs, current_dir, target, &mut warn),
+            ["dir", name] => match fs.child(current_dir, name) {
+                None => {
+                    fs.add_dir(current_dir, name);
+                }
+                Some(existing) if !fs.is_dir(existing) => warn(format!("{} is a file, not a directory", fs.path(existing))),
+                Some(_) => {}
+            },
+            [size, name] if usize::from_str(size).is_ok() => {
+                let size = usize::from_str(size).unwrap();
+                match fs.child(current_dir, name) {
+                    None => {
+                        fs.add_file(current_dir, name, size);
+                    }
+                    Some(existing) if fs.is_dir(existing) => warn(format!("{} is a directory, not a file", fs.path(existing))),
+                    Some(existing) if fs.size(existing) != size => {
+                        warn(format!("{} listed again with size {size} instead of {}, keeping the first", fs.path(existing), fs.size(existing)))
+                    }
+                    Some(_) => {}
+                }
+            }
+            _ => warn(format!("unknown line {line:?}")),
+        }
+    }
+    (fs, warnings)
+}
+
+// follows an absolute or relative path of directories, `cd ..` in the root stays there
+fn change_dir(fs: &mut FileSystem, current_dir: NodeId, target: &str, warn: &mut impl FnMut(String)) -> NodeId {
+    let mut dir = match target.starts_with('/') {
+        true => FileSystem::ROOT,
+        false => current_dir,
+    };
+    for name in target.split('/').filter(|name| !name.is_empty()) {
+        dir = match name {
+            "." => dir,
+            ".." => fs.parent(dir).unwrap_or(FileSystem::ROOT),
+            name => match fs.child(dir, name) {
+                Some(child) if fs.is_dir(child) => child,
+                Some(child) => {
+                    warn(format!("cannot cd into file {}, staying in {}", fs.path(child), fs.path(current_dir)));
+                    return current_dir;
+                }
+                None => {
+                    let child = fs.add_dir(dir, name);
+                    warn(format!("cd into directory {} which was not listed before, adding it", fs.path(child)));
+                    child
+                }
+            },
+        };
+    }
+    dir
+}
+
+pub fn day07() {
+    println!("starting day 07");
+
+    let contents = fs::read_to_string("data/2022/07_shell_output.txt").expect("Could not read file");
+
+    let (file_system, warnings) = parse_transcript(&contents);
+    warnings.iter().for_each(|warning| println!("Warning: {warning}"));
+
+    let directory_sizes = dir_sizes(&file_system);
+    let size_used = file_system.size(FileSystem::ROOT);
+    let total_size: usize = directory_sizes.iter().filter(|&it| *it <= 100000).sum();
+    println!("Part 1: Sum of files below size 100000: {:?}", total_size);
+    answers::check(1, total_size);
+    mem::report("part 1");
+
+    let total_disk_space: usize = 70000000;
+    let needed_free: usize = 30000000;
+    let current_free = total_disk_space.saturating_sub(size_used);
+    let need_to_be_freed = needed_free.saturating_sub(current_free);
+
+    let dir_to_del = file_system.descendants(FileSystem::ROOT)
+        .into_iter()
+        .filter(|&id| file_system.is_dir(id) && file_system.size(id) >= need_to_be_freed)
+        .min_by_key(|&id| file_system.size(id))
+        .unwrap();
+    let dir_size_to_del = file_system.size(dir_to_del);
+
+    println!("Current free disk space {:?}, need to free {:?}, size of smallest directory to delete ({}) is {:?}",
+             current_free,
+             need_to_be_freed,
+             file_system.path(dir_to_del),
+             dir_size_to_del);
+    answers::check(2, dir_size_to_del);
+
+    if let Some(path) = cli::option_value("--ls") {
+        match file_system.lookup(&path) {
+            Some(dir) => list(&file_system, dir),
+            None => println!("No such file or directory: {path}"),
+        }
+    }
+
+    let order = cli::parsed_option::<Order>("--sort").unwrap_or(Order::Listed);
+    if cli::has_flag("--tree") {
+        file_system.tree(FileSystem::ROOT, order).iter().for_each(|line| println!("{line}"));
+    }
+    if let Some(max_depth) = cli::parsed_option::<usize>("--du") {
+        for (dir, size) in file_system.du(FileSystem::ROOT, max_depth, order) {
+            println!("{size}\t{}", file_system.path(dir));
+        }
+    }
+    let size = cli::parsed_option::<SizeFilter>("--find-size");
+    let node_type = cli::parsed_option::<NodeType>("--type");
+    if size.is_some() || node_type.is_some() {
+        for node in file_system.find(FileSystem::ROOT, size, node_type, order) {
+            println!("{:>10} {}", file_system.size(node), file_system.path(node));
+        }
+    }
+}
+
+// like ls -l, with the total size of directories
+fn list(fs: &FileSystem, id: NodeId) {
+    let entries = match fs.is_dir(id) {
+        true => fs.children(id).collect::<Vec<NodeId>>(),
+        false => vec![id],
+    };
+    for entry in entries {
+        let kind = if fs.is_dir(entry) { "dir " } else { "file" };
+        println!("{kind} {:>10} {}", fs.size(entry), fs.path(entry));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_transcript, FileSystem, NodeId, NodeType, Order, SizeFilter};
+    use std::str::FromStr;
+
+    #[test]
+    fn names_with_dots_and_dashes() {
+        let (fs, warnings) = parse_transcript("$ cd /\n$ ls\ndir a-b.c\n12 x.y-z.txt\n$ cd a-b.c\n$ ls\n3 .hidden");
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(fs.size(fs.lookup("/x.y-z.txt").unwrap()), 12);
+        assert_eq!(fs.size(fs.lookup("/a-b.c/.hidden").unwrap()), 3);
+        assert_eq!(fs.size(FileSystem::ROOT), 15);
+    }
+
+    #[test]
+    fn cd_to_an_absolute_path() {
+        let (fs, warnings) = parse_transcript("$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\ndir b\n$ cd /a/b\n$ ls\n5 f\n$ cd /\n$ ls\n7 g");
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(fs.path(fs.lookup("/a/b/f").unwrap()), "/a/b/f");
+        assert_eq!(fs.path(fs.lookup("/g").unwrap()), "/g");
+        assert_eq!(fs.size(fs.lookup("/a").unwrap()), 5);
+    }
+
+    #[test]
+    fn cd_into_unlisted_directory() {
+        let (fs, warnings) = parse_transcript("$ cd /\n$ cd new\n$ ls\n4 f");
+        assert_eq!(warnings, vec!["line 2: cd into directory /new which was not listed before, adding it"]);
+        assert_eq!(fs.size(fs.lookup("/new").unwrap()), 4);
+    }
+
+    #[test]
+    fn cd_up_in_the_root_stays_there() {
+        let (fs, warnings) = parse_transcript("$ cd /\n$ cd ..\n$ ls\n4 f");
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert!(fs.lookup("/f").is_some());
+    }
+
+    #[test]
+    fn repeated_ls_adds_entries_once() {
+        let (fs, warnings) = parse_transcript("$ cd /\n$ ls\ndir a\n10 f\n$ ls\ndir a\n10 f\n$ ls\n11 f");
+        assert_eq!(warnings, vec!["line 9: /f listed again with size 11 instead of 10, keeping the first"]);
+        assert_eq!(fs.children(FileSystem::ROOT).count(), 2);
+        assert_eq!(fs.size(FileSystem::ROOT), 10);
+    }
+
+    #[test]
+    fn unknown_lines_are_skipped_with_a_warning() {
+        let (fs, warnings) = parse_transcript("$ cd /\n$ ls\n10 f\n$ pwd\n20 g");
+        assert_eq!(warnings, vec!["line 4: unknown line \"$ pwd\""]);
+        assert_eq!(fs.size(FileSystem::ROOT), 30);
+    }
+
+    /*
+     * /
+     *   a/        (total 120)
+     *     e/      (total 20)
+     *       i       20
+     *     f        100
+     *   b           50
+     *   d/        (total 7)
+     *     j          7
+     */
+    fn example() -> FileSystem {
+        let mut fs = FileSystem::new();
+        let a = fs.add_dir(FileSystem::ROOT, "a");
+        fs.add_file(FileSystem::ROOT, "b", 50);
+        let d = fs.add_dir(FileSystem::ROOT, "d");
+        let e = fs.add_dir(a, "e");
+        fs.add_file(a, "f", 100);
+        fs.add_file(e, "i", 20);
+        fs.add_file(d, "j", 7);
+        fs
+    }
+
+    fn paths(fs: &FileSystem, nodes: impl IntoIterator<Item = NodeId>) -> Vec<String> {
+        nodes.into_iter().map(|node| fs.path(node)).collect()
+    }
+
+    #[test]
+    fn du_limits_the_depth() {
+        let fs = example();
+        let du = |max_depth| {
+            fs.du(FileSystem::ROOT, max_depth, Order::Listed).into_iter().map(|(dir, size)| (fs.path(dir), size)).collect::<Vec<_>>()
+        };
+        assert_eq!(du(0), vec![(String::from("/"), 177)]);
+        assert_eq!(du(1), vec![(String::from("/a"), 120), (String::from("/d"), 7), (String::from("/"), 177)]);
+        assert_eq!(du(2).len(), 4);
+        assert_eq!(du(2)[0], (String::from("/a/e"), 20));
+        assert_eq!(du(5), du(2));
+        let by_size = fs.du(FileSystem::ROOT, 1, Order::Size);
+        assert_eq!(paths(&fs, by_size.into_iter().map(|(dir, _)| dir)), vec!["/a", "/d", "/"]);
+    }
+
+    #[test]
+    fn find_by_size_and_type() {
+        let fs = example();
+        let find = |size: Option<&str>, node_type| {
+            let size = size.map(|size| SizeFilter::from_str(size).unwrap());
+            paths(&fs, fs.find(FileSystem::ROOT, size, node_type, Order::Name))
+        };
+        assert_eq!(find(Some("+50"), None), vec!["/", "/a", "/a/f"]);
+        assert_eq!(find(Some("-20"), None), vec!["/d", "/d/j"]);
+        assert_eq!(find(Some("20"), None), vec!["/a/e", "/a/e/i"]);
+        assert_eq!(find(Some("20"), Some(NodeType::File)), vec!["/a/e/i"]);
+        assert_eq!(find(Some("+50"), Some(NodeType::Dir)), vec!["/", "/a"]);
+        assert_eq!(find(None, Some(NodeType::File)), vec!["/a/e/i", "/a/f", "/b", "/d/j"]);
+        assert_eq!(paths(&fs, fs.find(FileSystem::ROOT, None, Some(NodeType::File), Order::Size)), vec!["/a/f", "/b", "/a/e/i", "/d/j"]);
+    }
+
+    #[test]
+    fn size_filter_from_str() {
+        assert_eq!(SizeFilter::from_str("+100"), Ok(SizeFilter::Above(100)));
+        assert_eq!(SizeFilter::from_str("-100"), Ok(SizeFilter::Below(100)));
+        assert_eq!(SizeFilter::from_str("100"), Ok(SizeFilter::Exactly(100)));
+        assert!(SizeFilter::from_str("").is_err());
+        assert!(SizeFilter::from_str("+").is_err());
+        assert!(SizeFilter::from_str("1k").is_err());
+        assert!(SizeFilter::Above(100).matches(101) && !SizeFilter::Above(100).matches(100));
+        assert!(SizeFilter::Below(100).matches(99) && !SizeFilter::Below(100).matches(100));
+    }
+}
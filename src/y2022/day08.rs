@@ -1,6 +1,8 @@
 use std::cmp::max;
 use std::fs;
 use std::str::FromStr;
+use crate::answers;
+use crate::mem;
 
 fn is_visible_naive(grid: Vec<Vec<u8>>, x: usize, y: usize) -> bool {
     // max grid size (inclusive)
@@ -95,7 +97,7 @@ pub fn day08() {
     println!("starting day 08");
 
     let contents =
-        fs::read_to_string("data/08_input.txt").expect("Could not read file");
+        fs::read_to_string("data/2022/08_input.txt").expect("Could not read file");
 
     let lines = contents.split('\n');
 
@@ -119,6 +121,8 @@ pub fn day08() {
         }
     }
     println!("Number of visible trees {:?}", visible_count);
+    answers::check(1, visible_count);
+    mem::report("part 1");
 
     let mut top_score = 0;
     for (i, _) in grid.iter().enumerate() {
@@ -127,5 +131,6 @@ pub fn day08() {
         }
     }
     println!("Top scenic score: {:?}", top_score);
+    answers::check(2, top_score);
 }
 
@@ -2,6 +2,8 @@ use std::cmp::{max, min};
 use std::fs;
 use std::str::{FromStr, Split};
 use itertools::Itertools;
+use crate::answers;
+use crate::mem;
 
 fn dist(a: (i32, i32), b: (i32, i32)) -> f64 {
     let tmp = ((b.0 - a.0) as f64).powi(2) + ((b.1 - a.1) as f64).powi(2);
@@ -73,11 +75,12 @@ pub fn day09() {
     println!("starting day 09");
 
     let contents =
-        fs::read_to_string("data/09_input.txt").expect("Could not read file");
+        fs::read_to_string("data/2022/09_input.txt").expect("Could not read file");
 
     let lines = contents.split('\n');
 
     part_1(lines.clone());
+    mem::report("part 1");
     part_2(lines.clone());
 }
 
@@ -120,7 +123,8 @@ fn part_1(lines: Split<char>) {
     }
 
     let visited_count = visited.iter().unique().count();
-    println!("Fields visited by tail {visited_count}")
+    println!("Fields visited by tail {visited_count}");
+    answers::check(1, visited_count);
 }
 
 fn part_2(lines: Split<char>) {
@@ -169,6 +173,7 @@ fn part_2(lines: Split<char>) {
     }
 
     let visited_count = visited.iter().unique().count();
-    println!("Fields visited by tail {visited_count}")
+    println!("Fields visited by tail {visited_count}");
+    answers::check(2, visited_count);
 }
 
@@ -0,0 +1,67 @@
+use std::str::{FromStr};
+use crate::answers;
+use crate::input;
+use crate::mem;
+
+// the CRT draws one pixel per cycle, rows are printed as soon as they are complete
+struct Crt {
+    row: String,
+}
+
+impl Crt {
+    fn draw(&mut self, x: i32) {
+        let p = self.row.len() as i32;
+        if x-1 <= p && p < x+2 {
+            self.row.push('#');
+        } else {
+            self.row.push('.');
+        }
+        if self.row.len() == 40 {
+            println!("{:?}", self.row);
+            self.row.clear();
+        }
+    }
+}
+
+// calls on_cycle with the cycle number and the value of x during that cycle
+fn for_each_cycle(path: &str, mut on_cycle: impl FnMut(i32, i32)) {
+    let mut cycle = 0;
+    let mut x = 1;
+    let mut tick = |x: i32| {
+        cycle += 1;
+        on_cycle(cycle, x);
+    };
+
+    for line in input::lines(path) {
+        if line.starts_with("noop") {
+            tick(x);
+        } else if line.starts_with("addx ") {
+            let op = line.strip_prefix("addx ").and_then(|it| i32::from_str(it).ok()).unwrap();
+            tick(x);
+            tick(x);
+            x += op;
+        }
+    }
+}
+
+pub fn day10() {
+
+    println!("starting day 10");
+
+    let path = "data/2022/10_input.txt";
+    let interesting = [20, 60, 100, 140, 180, 220];
+    let mut cycle_sum = 0;
+    // the signal strength is sampled while the cycle runs
+    for_each_cycle(path, |cycle, x| {
+        if interesting.contains(&cycle) {
+            cycle_sum += cycle * x;
+        }
+    });
+
+    println!("Sum of signal strengths is {:?}", cycle_sum);
+    answers::check(1, cycle_sum);
+    mem::report("part 1");
+
+    let mut crt = Crt { row: String::with_capacity(40) };
+    for_each_cycle(path, |_, x| crt.draw(x));
+}
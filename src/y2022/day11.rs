@@ -3,7 +3,9 @@ use regex::{Captures, Regex};
 use std::any::Any;
 use std::str::{FromStr, Split};
 use std::{fmt, fs};
-use itertools::Itertools;
+use crate::answers;
+use crate::topk;
+use crate::mem;
 
 struct Monkey {
     items: Box<Vec<u64>>,
@@ -29,36 +31,33 @@ pub fn day11() {
 
     println!("starting day 11");
 
-    let contents = fs::read_to_string("data/11_input.txt").expect("Could not read file");
+    let contents = fs::read_to_string("data/2022/11_input.txt").expect("Could not read file");
     let lines = contents.split('\n');
 
     let mut monkeys = parse_input(lines.clone());
 
     simulate_n_rounds(&mut monkeys, 20, 3);
 
-    let monkey_business_level = monkeys.iter()
-        .map(|monkey| monkey.inspected_count)
-        .sorted()
-        .rev()
-        .take(2)
+    let monkey_business_level = topk::largest(monkeys.iter().map(|monkey| monkey.inspected_count), 2)
+        .into_iter()
         .inspect(|&it| println!("Inspection count {it}"))
         .fold(1, |acc, elem| acc * elem);
 
     println!("Part 1: Level of monkey business: {monkey_business_level}");
+    answers::check(1, monkey_business_level);
+    mem::report("part 1");
 
     monkeys = parse_input(lines.clone());
 
     simulate_rounds_part2(&mut monkeys, 10_000);
 
-    let monkey_business_level = monkeys.iter()
-        .map(|monkey| monkey.inspected_count)
-        .sorted()
-        .rev()
-        .take(2)
+    let monkey_business_level = topk::largest(monkeys.iter().map(|monkey| monkey.inspected_count), 2)
+        .into_iter()
         .inspect(|&it| println!("Inspection count {it}"))
         .fold(1 as u128, |acc, elem| acc * elem as u128);
 
     println!("Part 2: Level of monkey business: {monkey_business_level}");
+    answers::check(2, monkey_business_level);
 
 }
 
@@ -2,6 +2,8 @@ use std::collections::{HashMap, HashSet};
 use std::fs;
 
 use itertools::{Itertools};
+use crate::answers;
+use crate::mem;
 
 fn get_normalized_value(c: char) -> i32 {
     match c {
@@ -95,7 +97,7 @@ fn neighbors(&(y, x): &(usize, usize), grid: &Vec<Vec<char>>) -> Vec<(usize, usi
 pub fn day12() {
     println!("starting day 12");
 
-    let contents = fs::read_to_string("data/12_input.txt").expect("Could not read file");
+    let contents = fs::read_to_string("data/2022/12_input.txt").expect("Could not read file");
     let lines = contents.split('\n');
 
     let mut grid: Vec<Vec<char>> = vec![];
@@ -124,6 +126,8 @@ pub fn day12() {
     let cost = part1_shortest_path(&grid, &start, &target);
 
     println!("Cost of reaching E: {:?}", cost.get(&target).unwrap());
+    answers::check(1, cost.get(&target).unwrap());
+    mem::report("part 1");
 
     // just brute force it
     let mut min_dist_to_a = u32::MAX;
@@ -140,6 +144,7 @@ pub fn day12() {
 
         println!("Minimum distance to reach any a {:?}", min_dist_to_a);
     }
+    answers::check(2, min_dist_to_a);
 }
 
 fn part1_shortest_path(
@@ -1,8 +1,10 @@
-use crate::day13::Token::{List, Num};
+use crate::y2022::day13::Token::{List, Num};
 use itertools::Itertools;
 use std::cmp::Ordering;
 use std::cmp::Ordering::{Greater, Less};
 use std::fs;
+use crate::answers;
+use crate::mem;
 
 #[derive(Debug, Ord, Eq, PartialEq, Clone)]
 enum Token {
@@ -87,7 +89,7 @@ fn parse_list(s: &mut String) -> Token {
 pub fn day13() {
     println!("starting day 11");
 
-    let contents = fs::read_to_string("data/13_input.txt").expect("Could not read file");
+    let contents = fs::read_to_string("data/2022/13_input.txt").expect("Could not read file");
     let lines = contents.split('\n');
 
     let mut pair_cnt = 1;
@@ -124,6 +126,8 @@ pub fn day13() {
         "Part 1: Sum of indices of correct pairs is {}",
         correct_indices_count
     );
+    answers::check(1, correct_indices_count);
+    mem::report("part 1");
 
     let divider_2 = List(Box::new(vec![List(Box::new(vec![Num(2)]))]));
     let divider_6 = List(Box::new(vec![List(Box::new(vec![Num(6)]))]));
@@ -147,4 +151,5 @@ pub fn day13() {
         }
     }
     println!("Part 2: Decoder key is {:?}", decoder_key);
+    answers::check(2, decoder_key);
 }
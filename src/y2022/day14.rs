@@ -3,6 +3,8 @@ use regex::Regex;
 use std::collections::HashMap;
 use std::{fs};
 use std::str::Split;
+use crate::answers;
+use crate::mem;
 
 #[derive(Debug, Hash, Clone)]
 struct Coord {
@@ -30,7 +32,7 @@ enum Material {
 pub fn day14() {
     println!("starting day 14");
 
-    let contents = fs::read_to_string("data/14_input.txt").expect("Could not read file");
+    let contents = fs::read_to_string("data/2022/14_input.txt").expect("Could not read file");
 
     let lines = contents.split('\n');
     let (y_abyss_threshold, mut cave) = build_cave(lines.clone());
@@ -42,6 +44,8 @@ pub fn day14() {
         "Part 1: Number of placed units of sand: {:?}",
         placed_sand_units
     );
+    answers::check(1, placed_sand_units);
+    mem::report("part 1");
 
     // part 2 - for the sake of simplicity we add a real floor to the cave
     // another option would be adjusting the collision check, avoiding adding many coords to the cave
@@ -63,6 +67,7 @@ pub fn day14() {
         "Part 2: Number of placed units of sand until exit reached: {:?}",
         sand_units_until_exit_reached
     );
+    answers::check(2, sand_units_until_exit_reached);
 }
 
 fn part1_simulate_sand_falls_into_abyss(
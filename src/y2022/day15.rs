@@ -1,8 +1,11 @@
-use crate::day15::Object::{Beacon, Sensor};
+use crate::y2022::day15::Object::{Beacon, Sensor};
 use lazy_static::lazy_static;
 use regex::{Captures, Regex};
 use std::collections::{HashMap, HashSet};
 use std::fs;
+use crate::answers;
+use crate::interval::IntervalSet;
+use crate::mem;
 
 #[derive(Debug, Hash, Clone, Copy)]
 struct Coord {
@@ -23,13 +26,12 @@ enum Object {
     // beacon location and sensing distance (distance to beacon)
     Sensor(Coord, u32),
     Beacon(Coord),
-    Covered,
 }
 
 pub fn day15() {
     println!("starting day 15");
 
-    let contents = fs::read_to_string("data/15_input.txt").expect("Could not read file");
+    let contents = fs::read_to_string("data/2022/15_input.txt").expect("Could not read file");
 
     let lines = contents.split('\n');
 
@@ -70,58 +72,38 @@ pub fn day15() {
     println!("Parsed input - investigating area covered by sensors.");
 
     let target_line = 2000000;
-    let mut staging = HashMap::new();
+    let mut row_coverage = IntervalSet::new();
     for (coord, object) in objects.iter() {
-        staging.insert(*coord, *object);
         match object {
-            Sensor(beacon, _) => {
-                let dist = (coord.x - beacon.x).abs() + (coord.y - beacon.y).abs();
-
-                for y in (coord.y - dist)..=(coord.y + dist) {
-                    // optimization: only populate target line to save memory and runtime
-                    if y != target_line {
-                        continue
-                    }
-
-                    let x_span = dist - (coord.y - y).abs();
-
-                    for x in (coord.x - x_span)..=(coord.x + x_span) {
-                        if !staging.contains_key(&Coord { x, y }) {
-                            staging.insert(Coord { x, y }, Object::Covered);
-                        }
-                    }
-                }
+            Sensor(_, dist) => {
+                // only the target line is of interest, a sensor covers a span of it if in reach
+                let x_span = *dist as i64 - (coord.y - target_line).abs() as i64;
+                row_coverage.insert(coord.x as i64 - x_span, coord.x as i64 + x_span);
             }
             Beacon(_) => {}
-            Object::Covered => {}
         }
     }
 
-    objects = staging;
-
     println!("Calculated coverage. Finding covered area on line {target_line}");
     //print_map(&mut objects);
 
-    let line_exclusions = objects
-        .iter()
-        .filter(|(coord, _)| coord.y == target_line)
-        .filter(|(_, &object)| match object {
-            Sensor(_, _) => false,
-            Beacon(_) => false,
-            Object::Covered => true,
-        })
-        .filter(|(coord, _)| coord.y == target_line)
+    // sensors and beacons on the line are covered, but are no candidates for another beacon
+    let occupied = objects
+        .keys()
+        .filter(|coord| coord.y == target_line && row_coverage.contains(coord.x as i64))
         .count();
+    let line_exclusions = row_coverage.len() as usize - occupied;
     println!(
         "Part 1: In row where y={target_line}, {line_exclusions} positions cannot contain a beacon"
     );
+    answers::check(1, line_exclusions);
+    mem::report("part 1");
 
     let sensor_coords = objects
         .iter()
         .filter(|(_, o)| match o {
             Sensor(_, _) => true,
             Beacon(_) => false,
-            Object::Covered => false,
         })
         .collect::<Vec<(&Coord, &Object)>>();
 
@@ -137,7 +119,6 @@ pub fn day15() {
                 });
             }
             Beacon(_) => {}
-            Object::Covered => {}
         }
     }
 
@@ -156,12 +137,13 @@ pub fn day15() {
                     }
                 }
                 Beacon(_) => panic!("Invalid object type"),
-                Object::Covered => panic!("Invalid object type"),
             }
         }
         if outside_all_sensors {
             println!("Found target coordinates at {:?}", coord);
-            println!("Tuning frequency is {:?}", coord.x as u64 * 4000000 + coord.y as u64);
+            let tuning_frequency = coord.x as u64 * 4000000 + coord.y as u64;
+            println!("Tuning frequency is {:?}", tuning_frequency);
+            answers::check(2, tuning_frequency);
             break 'search;
         }
     }
@@ -211,7 +193,6 @@ fn print_map(objects: &mut HashMap<Coord, Object>) {
                 Some(material) => match material {
                     Sensor(_, _) => print!("S"),
                     Beacon(_) => print!("B"),
-                    Object::Covered => print!("#"),
                 },
                 None => print!("."),
             }
@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use std::fs;
 
 use itertools::Itertools;
+use crate::answers;
 
 #[derive(Debug, Hash, Clone)]
 enum Action<'a> {
@@ -120,7 +121,7 @@ fn most_promising_candidates(time_left: u32, state: State) -> BacktrackingResult
 pub fn day16() {
     println!("starting day 16");
 
-    let contents = fs::read_to_string("data/16_demo.txt").expect("Could not read file");
+    let contents = fs::read_to_string("data/2022/16_demo.txt").expect("Could not read file");
 
     let lines = contents.split('\n');
 
@@ -214,6 +215,7 @@ pub fn day16() {
         "Part 1: Total released pressure: {:?}",
         result.max_pressure_released
     );
+    answers::check(1, result.max_pressure_released);
     for (min, action) in result.best_actions.iter().sorted_by_key(|&it| -(*it.0 as i32)) {
         println!("{:?}: {:?}", time_left - min, action);
     }
@@ -0,0 +1,38 @@
+use crate::Solver;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+
+// `cargo run new 2022 NN` appends new days to this list
+pub const SOLVERS: &[(&str, Solver)] = &[
+    ("01", day01::day01),
+    ("02", day02::day02),
+    ("03", day03::day03),
+    ("04", day04::day04),
+    ("05", day05::day05),
+    ("06", day06::day06),
+    ("07", day07::day07),
+    ("08", day08::day08),
+    ("09", day09::day09),
+    ("10", day10::day10),
+    ("11", day11::day11),
+    ("12", day12::day12),
+    ("13", day13::day13),
+    ("14", day14::day14),
+    ("15", day15::day15),
+    ("16", day16::day16),
+];